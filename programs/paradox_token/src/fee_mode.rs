@@ -0,0 +1,148 @@
+/**
+ * Transfer Fee Mode Helpers
+ * 
+ * Token-2022 withholds the transfer fee from the destination, so a
+ * transfer_checked of `amount` delivers `amount - fee`. Vaults that pay out
+ * (vesting, treasury) pick which side of that the requested amount means:
+ * 
+ * - Net:     transfer `amount`, recipient receives `amount - fee` (legacy)
+ * - GrossUp: transfer `amount + fee`, recipient receives exactly `amount`
+ * 
+ * Fees are computed from the mint's TransferFeeConfig for the current epoch.
+ * Mints without the extension (or legacy SPL mints) have a fee of 0.
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::{
+    extension::{transfer_fee::TransferFeeConfig, BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as SplMint,
+};
+
+use crate::ParadoxError;
+
+/// How a payout amount relates to the transfer fee (stored as u8 on vaults)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FeeMode {
+    /// Transfer `amount`, recipient bears the fee
+    Net = 0,
+    /// Transfer `amount + fee`, vault bears the fee
+    GrossUp = 1,
+}
+
+impl FeeMode {
+    pub fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0 => Some(Self::Net),
+            1 => Some(Self::GrossUp),
+            _ => None,
+        }
+    }
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Net => "NET",
+            Self::GrossUp => "GROSS_UP",
+        }
+    }
+}
+
+/// Gross (transferred) and net (received) amounts for a payout of `amount`
+/// Returns (gross, net)
+pub fn payout_amounts(mint: &AccountInfo, amount: u64, mode: FeeMode) -> Result<(u64, u64)> {
+    if amount == 0 {
+        return Ok((0, 0));
+    }
+    
+    payout_amounts_at(mint, amount, mode, Clock::get()?.epoch)
+}
+
+/// payout_amounts for the fee in effect at `epoch`
+pub fn payout_amounts_at(mint: &AccountInfo, amount: u64, mode: FeeMode, epoch: u64) -> Result<(u64, u64)> {
+    if amount == 0 {
+        return Ok((0, 0));
+    }
+    
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<SplMint>::unpack(&data)?;
+    
+    let config = match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => config,
+        Err(_) => return Ok((amount, amount)),
+    };
+    
+    match mode {
+        FeeMode::Net => {
+            let fee = config
+                .calculate_epoch_fee(epoch, amount)
+                .ok_or(ParadoxError::MathOverflow)?;
+            Ok((amount, amount.saturating_sub(fee)))
+        }
+        FeeMode::GrossUp => {
+            // Returns the fee charged on the pre-fee amount that nets `amount`
+            let fee = config
+                .calculate_inverse_epoch_fee(epoch, amount)
+                .ok_or(ParadoxError::MathOverflow)?;
+            let gross = amount.checked_add(fee).ok_or(ParadoxError::MathOverflow)?;
+            Ok((gross, amount))
+        }
+    }
+}
+
+/// Fees withheld on the mint itself (TransferFeeConfig.withheld_amount)
+/// 0 when the mint has no transfer fee extension
+pub fn mint_withheld_amount(mint: &AccountInfo) -> Result<u64> {
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<SplMint>::unpack(&data)?;
+    
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(u64::from(config.withheld_amount)),
+        Err(_) => Ok(0),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use anchor_spl::token_interface::spl_token_2022::extension::{
+        transfer_fee::TransferFee, BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+    };
+    
+    /// Token-2022 mint data with a TransferFeeConfig charging `fee_bps` (capped at `maximum_fee`)
+    pub(crate) fn mint_with_transfer_fee(fee_bps: u16, maximum_fee: u64) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<SplMint>(&[ExtensionType::TransferFeeConfig]).unwrap();
+        let mut data = vec![0u8; len];
+        let mut state = StateWithExtensionsMut::<SplMint>::unpack_uninitialized(&mut data).unwrap();
+        
+        let fee = TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: fee_bps.into(),
+        };
+        let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+        config.older_transfer_fee = fee;
+        config.newer_transfer_fee = fee;
+        
+        state.base = SplMint { decimals: 9, is_initialized: true, ..Default::default() };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
+    
+    #[test]
+    fn net_and_gross_up_against_transfer_fee() {
+        let mut data = mint_with_transfer_fee(100, u64::MAX);
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 0;
+        let mint = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        
+        // 1%: the recipient bears the fee...
+        assert_eq!(payout_amounts_at(&mint, 10_000, FeeMode::Net, 0).unwrap(), (10_000, 9_900));
+        // ...or the sender pays it on top so exactly `amount` arrives
+        assert_eq!(payout_amounts_at(&mint, 9_900, FeeMode::GrossUp, 0).unwrap(), (10_000, 9_900));
+        
+        assert_eq!(payout_amounts_at(&mint, 0, FeeMode::GrossUp, 0).unwrap(), (0, 0));
+    }
+}
@@ -10,11 +10,21 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    state::{ArmageddonState, TokenConfig},
+    state::{
+        ArmageddonState, ArmageddonStatus, TokenConfig,
+        DEFAULT_THRESHOLD_L1_BPS, DEFAULT_THRESHOLD_L2_BPS, DEFAULT_THRESHOLD_L3_BPS,
+        MIN_RECOVERY_THRESHOLD_BPS, MAX_RECOVERY_THRESHOLD_BPS,
+    },
+    lp_value::lp_value_from_reserves,
     ParadoxError,
     TOKEN_CONFIG_SEED,
+    CURRENT_ACCOUNT_VERSION,
     ArmageddonTriggered,
     ArmageddonRecovered,
+    BaselineLpValueSet,
+    ArmageddonInitialized,
+    ArmageddonRecoveryAuthorityUpdated,
+    RecoveryThresholdUpdated,
 };
 
 /// Seed for ArmageddonState PDA
@@ -48,7 +58,21 @@ pub struct InitArmageddon<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn init_armageddon_handler(ctx: Context<InitArmageddon>) -> Result<()> {
+pub fn init_armageddon_handler(
+    ctx: Context<InitArmageddon>,
+    threshold_l1_bps: Option<u16>,
+    threshold_l2_bps: Option<u16>,
+    threshold_l3_bps: Option<u16>,
+) -> Result<()> {
+    let threshold_l1_bps = threshold_l1_bps.unwrap_or(DEFAULT_THRESHOLD_L1_BPS);
+    let threshold_l2_bps = threshold_l2_bps.unwrap_or(DEFAULT_THRESHOLD_L2_BPS);
+    let threshold_l3_bps = threshold_l3_bps.unwrap_or(DEFAULT_THRESHOLD_L3_BPS);
+    
+    require!(
+        ArmageddonState::validate_thresholds(threshold_l1_bps, threshold_l2_bps, threshold_l3_bps),
+        ParadoxError::InvalidThresholds
+    );
+    
     let state = &mut ctx.accounts.armageddon_state;
     
     state.token_config = ctx.accounts.token_config.key();
@@ -64,21 +88,154 @@ pub fn init_armageddon_handler(ctx: Context<InitArmageddon>) -> Result<()> {
     state.trading_paused = false;
     state.max_pause_duration = 24 * 60 * 60; // 24h max
     state.bump = ctx.bumps.armageddon_state;
+    state.threshold_l1_bps = threshold_l1_bps;
+    state.threshold_l2_bps = threshold_l2_bps;
+    state.threshold_l3_bps = threshold_l3_bps;
+    state.is_initialized = true;
+    state.version = CURRENT_ACCOUNT_VERSION;
+    state.pre_armageddon_saved = false;
+    
+    emit!(ArmageddonInitialized {
+        token_config: state.token_config,
+        threshold_l1_bps,
+        threshold_l2_bps,
+        threshold_l3_bps,
+    });
     
     msg!("Armageddon state initialized");
     Ok(())
 }
 
+// =============================================================================
+// SET BASELINE LP VALUE
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetBaselineLpValue<'info> {
+    /// Admin or governance
+    #[account(
+        constraint = authority.key() == token_config.admin
+            || authority.key() == token_config.governance @ ParadoxError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        seeds = [ARMAGEDDON_SEED, token_config.key().as_ref()],
+        bump = armageddon_state.bump,
+        constraint = armageddon_state.token_config == token_config.key() @ ParadoxError::Unauthorized,
+    )]
+    pub armageddon_state: Account<'info, ArmageddonState>,
+}
+
+pub fn set_baseline_handler(ctx: Context<SetBaselineLpValue>, baseline_lp_value: u64) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.armageddon_state.is_initialized(), ParadoxError::NotInitialized);
+    
+    require!(baseline_lp_value > 0, ParadoxError::BaselineNotSet);
+    
+    let state = &mut ctx.accounts.armageddon_state;
+    
+    // Baseline is the healthy reference - don't move it mid-emergency
+    require!(state.level == 0, ParadoxError::ArmageddonActive);
+    
+    let old_baseline = state.baseline_lp_value;
+    state.baseline_lp_value = baseline_lp_value;
+    
+    emit!(BaselineLpValueSet {
+        token_config: state.token_config,
+        old_baseline,
+        new_baseline: baseline_lp_value,
+        set_by: ctx.accounts.authority.key(),
+    });
+    
+    msg!("Baseline LP value set: {} → {}", old_baseline, baseline_lp_value);
+    Ok(())
+}
+
+// =============================================================================
+// EVALUATE ARMAGEDDON LEVEL (read-only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct EvaluateArmageddon<'info> {
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        seeds = [ARMAGEDDON_SEED, token_config.key().as_ref()],
+        bump = armageddon_state.bump,
+        constraint = armageddon_state.token_config == token_config.key() @ ParadoxError::Unauthorized,
+    )]
+    pub armageddon_state: Account<'info, ArmageddonState>,
+}
+
+/// Returns the level warranted by current_lp_value against configured thresholds
+pub fn evaluate_handler(ctx: Context<EvaluateArmageddon>, current_lp_value: u64) -> Result<u8> {
+    require!(ctx.accounts.armageddon_state.is_initialized(), ParadoxError::NotInitialized);
+    
+    let state = &ctx.accounts.armageddon_state;
+    
+    require!(state.baseline_lp_value > 0, ParadoxError::BaselineNotSet);
+    
+    let level = state.evaluate_level(current_lp_value);
+    msg!("LP value {} vs baseline {} → level {}", current_lp_value, state.baseline_lp_value, level);
+    
+    Ok(level)
+}
+
+/// Same as evaluate_handler, computing LP value from pool reserves
+/// See lp_value.rs for the price source assumption
+pub fn evaluate_from_reserves_handler(
+    ctx: Context<EvaluateArmageddon>,
+    sol_reserve: u64,
+    token_reserve: u64,
+    token_price_in_sol: u64,
+) -> Result<u8> {
+    let current_lp_value = lp_value_from_reserves(sol_reserve, token_reserve, token_price_in_sol)?;
+    evaluate_handler(ctx, current_lp_value)
+}
+
+// =============================================================================
+// GET ARMAGEDDON STATUS (return data)
+// =============================================================================
+
+pub fn get_status_handler(ctx: Context<EvaluateArmageddon>) -> Result<ArmageddonStatus> {
+    let status = ctx.accounts.armageddon_state.get_status(Clock::get()?.unix_timestamp)?;
+    
+    msg!("╔══════════════════════════════════════════╗");
+    msg!("║        ARMAGEDDON STATUS                 ║");
+    msg!("╠══════════════════════════════════════════╣");
+    msg!("║ Level: {} ({})", status.level, ArmageddonState::get_response(status.level));
+    msg!("║ Trading paused: {} (expires {})", status.pause_active, status.pause_expires_at);
+    msg!("║ Baseline LP: {} | At trigger: {}", status.baseline_lp_value, status.lp_value_at_trigger);
+    msg!("║ Recovery target: {} ({} bps)", status.recovery_target, status.recovery_threshold_bps);
+    msg!("╚══════════════════════════════════════════╝");
+    
+    Ok(status)
+}
+
 // =============================================================================
 // TRIGGER ARMAGEDDON
 // =============================================================================
 
 #[derive(Accounts)]
 pub struct TriggerArmageddon<'info> {
+    /// Admin (levels 1-2) or governance (any level)
     #[account(
-        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+        constraint = authority.key() == token_config.admin
+            || authority.key() == token_config.governance @ ParadoxError::Unauthorized
     )]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
     
     #[account(
         mut,
@@ -96,42 +253,77 @@ pub struct TriggerArmageddon<'info> {
     pub armageddon_state: Account<'info, ArmageddonState>,
 }
 
-pub fn trigger_handler(ctx: Context<TriggerArmageddon>, level: u8) -> Result<()> {
+pub fn trigger_handler(
+    ctx: Context<TriggerArmageddon>,
+    level: u8,
+    current_lp_value: u64,
+) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.armageddon_state.is_initialized(), ParadoxError::NotInitialized);
+    
     require!(level >= 1 && level <= 3, ParadoxError::InvalidArmageddonLevel);
     
+    // Thresholds are meaningless without a healthy reference point
+    require!(ctx.accounts.armageddon_state.baseline_lp_value > 0, ParadoxError::BaselineNotSet);
+    
+    // SECURITY: DEFCON 1 pauses trading - a single admin key can't reach it alone,
+    // including on older configs where governance was initialized to the admin
+    require!(
+        ctx.accounts.token_config.can_trigger_level(level, &ctx.accounts.authority.key()),
+        ParadoxError::GovernanceRequired
+    );
+    
     let config = &mut ctx.accounts.token_config;
     let state = &mut ctx.accounts.armageddon_state;
     let clock = Clock::get()?;
     
+    // Remember normal-operation values once - escalating keeps the original snapshot
+    if state.level == 0 {
+        state.pre_armageddon_fee_bps = config.transfer_fee_bps;
+        state.pre_armageddon_lp_share_bps = config.lp_share_bps;
+        state.pre_armageddon_burn_share_bps = config.burn_share_bps;
+        state.pre_armageddon_treasury_share_bps = config.treasury_share_bps;
+        state.pre_armageddon_saved = true;
+    }
+    
     // Set Armageddon level
     state.level = level;
     state.triggered_at = clock.unix_timestamp;
     config.armageddon_level = level;
     
+    // Observed LP value is the recovery reference - always refreshed, never left stale
+    state.lp_value_at_trigger = current_lp_value;
+    let lp_drop_bps = state.drop_bps(current_lp_value);
+    
+    // "Max fees" means this config's own ceiling - never above what it promised
+    let max_fee_bps = config.fee_bounds().1;
+    
     // Apply emergency measures based on level
     match level {
         1 => {
             // DEFCON 3: Max fees, high LP share
-            config.transfer_fee_bps = 300;
+            config.transfer_fee_bps = max_fee_bps;
             state.emergency_lp_share_bps = 9000;
         },
         2 => {
             // DEFCON 2: Above + Treasury injection prep
-            config.transfer_fee_bps = 300;
+            config.transfer_fee_bps = max_fee_bps;
             state.emergency_lp_share_bps = 9000;
         },
         3 => {
             // DEFCON 1: Above + Trading slowdown
-            config.transfer_fee_bps = 300;
+            config.transfer_fee_bps = max_fee_bps;
             state.emergency_lp_share_bps = 9000;
             state.trading_paused = true;
         },
         _ => {}
     }
+    config.refresh_min_transfer_amount();
     
     emit!(ArmageddonTriggered {
         level,
-        lp_drop_percent: ArmageddonState::get_threshold(level),
+        threshold_percent: (state.get_threshold(level) / 100) as u8,
+        lp_drop_percent: (lp_drop_bps / 100) as u8,
         response: ArmageddonState::get_response(level).to_string(),
     });
     
@@ -144,10 +336,11 @@ pub fn trigger_handler(ctx: Context<TriggerArmageddon>, level: u8) -> Result<()>
 
 #[derive(Accounts)]
 pub struct RecoverArmageddon<'info> {
+    /// Must be the configured recovery authority (not necessarily the triggerer)
     #[account(
-        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+        constraint = recovery_authority.key() == armageddon_state.recovery_authority @ ParadoxError::Unauthorized
     )]
-    pub admin: Signer<'info>,
+    pub recovery_authority: Signer<'info>,
     
     #[account(
         mut,
@@ -165,11 +358,16 @@ pub struct RecoverArmageddon<'info> {
     pub armageddon_state: Account<'info, ArmageddonState>,
 }
 
-pub fn recover_handler(ctx: Context<RecoverArmageddon>) -> Result<()> {
+/// current_lp_value must reach recovery_target() (trigger value × recovery_threshold_bps)
+pub fn recover_handler(ctx: Context<RecoverArmageddon>, current_lp_value: u64) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.armageddon_state.is_initialized(), ParadoxError::NotInitialized);
+    
     let config = &mut ctx.accounts.token_config;
     let state = &mut ctx.accounts.armageddon_state;
     
     require!(state.level > 0, ParadoxError::NotInArmageddon);
+    require!(current_lp_value >= state.recovery_target()?, ParadoxError::LpNotRecovered);
     
     let previous_level = state.level;
     
@@ -178,10 +376,135 @@ pub fn recover_handler(ctx: Context<RecoverArmageddon>) -> Result<()> {
     state.trading_paused = false;
     config.armageddon_level = 0;
     
+    // Restore pre-trigger fee and shares (otherwise the token stays at max fee)
+    if state.pre_armageddon_saved {
+        config.transfer_fee_bps = state.pre_armageddon_fee_bps;
+        config.lp_share_bps = state.pre_armageddon_lp_share_bps;
+        config.burn_share_bps = state.pre_armageddon_burn_share_bps;
+        config.treasury_share_bps = state.pre_armageddon_treasury_share_bps;
+        config.refresh_min_transfer_amount();
+        state.pre_armageddon_saved = false;
+    } else {
+        msg!("No pre-Armageddon snapshot - fee left at {} bps", config.transfer_fee_bps);
+    }
+    
     emit!(ArmageddonRecovered {
         previous_level,
-        lp_recovery_percent: 120,
+        restored_fee_bps: config.transfer_fee_bps,
+        lp_recovery_percent: (state.recovery_threshold_bps / 100) as u8,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// SET RECOVERY AUTHORITY
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetRecoveryAuthority<'info> {
+    /// Governance only - keeps recovery out of the admin's sole control
+    #[account(
+        constraint = governance.key() == token_config.governance @ ParadoxError::GovernanceRequired
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        seeds = [ARMAGEDDON_SEED, token_config.key().as_ref()],
+        bump = armageddon_state.bump,
+        constraint = armageddon_state.token_config == token_config.key() @ ParadoxError::Unauthorized,
+    )]
+    pub armageddon_state: Account<'info, ArmageddonState>,
+}
+
+pub fn set_recovery_authority_handler(
+    ctx: Context<SetRecoveryAuthority>,
+    new_recovery_authority: Pubkey,
+) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.armageddon_state.is_initialized(), ParadoxError::NotInitialized);
+    
+    // A zero key would make recovery impossible
+    require!(
+        new_recovery_authority != Pubkey::default(),
+        ParadoxError::InvalidRecoveryAuthority
+    );
+    
+    let state = &mut ctx.accounts.armageddon_state;
+    let old_authority = state.recovery_authority;
+    state.recovery_authority = new_recovery_authority;
+    
+    emit!(ArmageddonRecoveryAuthorityUpdated {
+        token_config: state.token_config,
+        old_authority,
+        new_authority: new_recovery_authority,
+    });
+    
+    msg!("Recovery authority: {} → {}", old_authority, new_recovery_authority);
+    Ok(())
+}
+
+// =============================================================================
+// SET RECOVERY THRESHOLD
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetRecoveryThreshold<'info> {
+    /// Recovery authority or governance
+    #[account(
+        constraint = authority.key() == armageddon_state.recovery_authority
+            || authority.key() == token_config.governance @ ParadoxError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        seeds = [ARMAGEDDON_SEED, token_config.key().as_ref()],
+        bump = armageddon_state.bump,
+        constraint = armageddon_state.token_config == token_config.key() @ ParadoxError::Unauthorized,
+    )]
+    pub armageddon_state: Account<'info, ArmageddonState>,
+}
+
+/// Lower (or raise) the LP recovery bar within 100%..=200% of the trigger value
+pub fn set_recovery_threshold_handler(
+    ctx: Context<SetRecoveryThreshold>,
+    recovery_threshold_bps: u16,
+) -> Result<()> {
+    require!(ctx.accounts.armageddon_state.is_initialized(), ParadoxError::NotInitialized);
+    require!(
+        (MIN_RECOVERY_THRESHOLD_BPS..=MAX_RECOVERY_THRESHOLD_BPS).contains(&recovery_threshold_bps),
+        ParadoxError::InvalidRecoveryThreshold
+    );
+    
+    let state = &mut ctx.accounts.armageddon_state;
+    let old_threshold_bps = state.recovery_threshold_bps;
+    state.recovery_threshold_bps = recovery_threshold_bps;
+    
+    // Reject a bar that can't even be represented for this trigger value
+    let recovery_target = state.recovery_target()?;
+    
+    emit!(RecoveryThresholdUpdated {
+        token_config: state.token_config,
+        old_threshold_bps,
+        new_threshold_bps: recovery_threshold_bps,
+        updated_by: ctx.accounts.authority.key(),
     });
     
+    msg!("Recovery threshold: {} bps → {} bps (target now {})",
+         old_threshold_bps, recovery_threshold_bps, recovery_target);
     Ok(())
 }
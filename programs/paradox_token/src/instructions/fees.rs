@@ -1,17 +1,52 @@
 /**
  * Fee Distribution Instructions
  * 
+ * INTERNAL TRANSFERS ARE FEE-BEARING
+ * Token-2022 has no per-account fee exemption: every transfer_checked out of
+ * the fee vault is charged the transfer fee, withheld on the destination.
+ * harvest_and_distribute pulls that withheld fee straight back from the LP
+ * growth and treasury accounts into the fee vault (the harvest authority PDA
+ * is the mint's withdraw-withheld authority), so it is redistributed on the
+ * next run instead of lost. Destinations are credited the net amount.
+ * 
  * Made by LabsX402 for Solana
  * https://x.com/LabsX402
  */
 
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::ID as TOKEN_2022_PROGRAM_ID;
+use anchor_spl::token_interface::{
+    TokenInterface, TokenAccount, Mint,
+    TransferChecked, transfer_checked,
+    Burn, burn,
+    InterfaceAccount, Interface,
+};
 
 use crate::{
-    state::TokenConfig,
+    state::{TokenConfig, LpGrowthManager, DaoTreasuryVault, ProgramStats},
+    ParadoxError,
     FeesDistributed,
+    LpGrowthFeesAccumulated,
+    FeesBurned,
+    FeesHarvested,
+    FeeDustSwept,
     TOKEN_CONFIG_SEED,
+    LP_GROWTH_SEED,
+    DAO_TREASURY_SEED,
+    fee_mode::{FeeMode, payout_amounts_at},
 };
+use super::harvest_fees::{HARVEST_AUTHORITY_SEED, withdraw_withheld_from_accounts};
+
+/// The three outputs must account for every token pulled from the vault
+/// (catches rounding drift in calculate_distribution before anything moves)
+pub fn check_distribution_sum(total_fees: u64, to_lp: u64, to_burn: u64, to_treasury: u64) -> Result<()> {
+    let sum = to_lp
+        .checked_add(to_burn)
+        .and_then(|v| v.checked_add(to_treasury))
+        .ok_or(ParadoxError::MathOverflow)?;
+    require!(sum == total_fees, ParadoxError::DistributionMismatch);
+    Ok(())
+}
 
 #[derive(Accounts)]
 pub struct DistributeFees<'info> {
@@ -32,6 +67,10 @@ pub struct DistributeFees<'info> {
 }
 
 pub fn distribute_handler(ctx: Context<DistributeFees>) -> Result<()> {
+    log_compute!("distribute_fees: entry");
+    
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
     let config = &mut ctx.accounts.token_config;
     
     // DEV: Get collected fees from vault
@@ -44,21 +83,26 @@ pub fn distribute_handler(ctx: Context<DistributeFees>) -> Result<()> {
     
     // Calculate distribution
     let (to_lp, to_burn, to_treasury) = config.calculate_distribution(total_fees)?;
+    check_distribution_sum(total_fees, to_lp, to_burn, to_treasury)?;
     
     // DEV: Implement actual transfers
     //
     // 1. Transfer to LP Growth Manager
     //    transfer(&ctx.accounts.fee_vault, &ctx.accounts.lp_destination, to_lp)?;
     //
-    // 2. Burn tokens
+    // 2. Burn tokens, then count them in config.total_burned
     //    burn(&ctx.accounts.fee_vault, to_burn)?;
     //
     // 3. Transfer to treasury
     //    transfer(&ctx.accounts.fee_vault, &ctx.accounts.treasury_account, to_treasury)?;
+    //
+    // 4. Reconcile: reload fee_vault and require it dropped by exactly total_fees
+    //    (DistributionMismatch otherwise - see harvest_and_distribute_handler)
     
     msg!("Fee distribution: LP={}, Burn={}, Treasury={}", to_lp, to_burn, to_treasury);
     
     // Update tracking (checked arithmetic)
+    // total_burned only counts real burns - nothing is burned here until step 2 exists
     config.total_fees_distributed = config.total_fees_distributed
         .checked_add(total_fees)
         .ok_or(crate::ParadoxError::MathOverflow)?;
@@ -73,3 +117,869 @@ pub fn distribute_handler(ctx: Context<DistributeFees>) -> Result<()> {
     Ok(())
 }
 
+
+// =============================================================================
+// PREVIEW DISTRIBUTION (read-only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct PreviewDistribution<'info> {
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+}
+
+/// Preview how a fee amount would be split (to_lp, to_burn, to_treasury)
+/// Treasury receives the rounding remainder, same as real distribution
+pub fn preview_distribution_handler(
+    ctx: Context<PreviewDistribution>,
+    fee_amount: u64,
+) -> Result<(u64, u64, u64)> {
+    ctx.accounts.token_config.calculate_distribution(fee_amount)
+}
+
+// =============================================================================
+// BURN FEES
+// =============================================================================
+
+/// Burn tokens from the fee vault (anchor-spl 0.30 has no burn_checked CPI wrapper)
+/// Signed by the harvest authority PDA (fee vault owner)
+pub fn burn_from_fee_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    fee_vault: &InterfaceAccount<'info, TokenAccount>,
+    authority: &AccountInfo<'info>,
+    authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        HARVEST_AUTHORITY_SEED,
+        mint_key.as_ref(),
+        &[authority_bump],
+    ]];
+    
+    burn(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: fee_vault.to_account_info(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// Transfer tokens out of the fee vault, signed by the harvest authority PDA
+pub fn transfer_from_fee_vault<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    fee_vault: &InterfaceAccount<'info, TokenAccount>,
+    to: AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let mint_key = mint.key();
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        HARVEST_AUTHORITY_SEED,
+        mint_key.as_ref(),
+        &[authority_bump],
+    ]];
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: fee_vault.to_account_info(),
+                to,
+                authority: authority.clone(),
+                mint: mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        mint.decimals,
+    )
+}
+
+/// What the LP growth and treasury accounts receive from fee vault transfers of
+/// `to_lp` / `to_treasury` under the transfer fee in effect at `epoch`
+/// Returns (lp_received, treasury_received)
+pub fn internal_transfer_receipts_at(
+    mint: &AccountInfo,
+    to_lp: u64,
+    to_treasury: u64,
+    epoch: u64,
+) -> Result<(u64, u64)> {
+    let (_, lp_received) = payout_amounts_at(mint, to_lp, FeeMode::Net, epoch)?;
+    let (_, treasury_received) = payout_amounts_at(mint, to_treasury, FeeMode::Net, epoch)?;
+    Ok((lp_received, treasury_received))
+}
+
+/// Pull the transfer fee withheld on the LP growth and treasury accounts by the
+/// split back into the fee vault (see module doc)
+/// Returns (lp_received, treasury_received) - the amounts to credit
+#[allow(clippy::too_many_arguments)]
+pub fn reclaim_internal_transfer_fees<'info>(
+    token_program: &Interface<'info, TokenInterface>,
+    mint: &InterfaceAccount<'info, Mint>,
+    fee_vault: &InterfaceAccount<'info, TokenAccount>,
+    lp_growth_token_account: AccountInfo<'info>,
+    treasury_token_account: AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    authority_bump: u8,
+    to_lp: u64,
+    to_treasury: u64,
+) -> Result<(u64, u64)> {
+    let mint_info = mint.to_account_info();
+    let (lp_received, treasury_received) =
+        internal_transfer_receipts_at(&mint_info, to_lp, to_treasury, Clock::get()?.epoch)?;
+    
+    if lp_received < to_lp || treasury_received < to_treasury {
+        withdraw_withheld_from_accounts(
+            &token_program.to_account_info(),
+            &mint_info,
+            &fee_vault.to_account_info(),
+            authority,
+            authority_bump,
+            &[lp_growth_token_account, treasury_token_account],
+        )?;
+        msg!("Reclaimed internal transfer fees: LP {} / Treasury {} received net",
+             lp_received, treasury_received);
+    }
+    
+    Ok((lp_received, treasury_received))
+}
+
+#[derive(Accounts)]
+pub struct BurnFees<'info> {
+    #[account(
+        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        constraint = fee_vault.key() == token_config.fee_vault @ ParadoxError::InvalidVault,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Harvest authority PDA (fee vault owner)
+    /// CHECK: PDA derived from mint - validated by seeds
+    #[account(
+        seeds = [HARVEST_AUTHORITY_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub harvest_authority: UncheckedAccount<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Burn part of the fee vault independently of full distribution
+pub fn burn_fees_handler(ctx: Context<BurnFees>, amount: u64) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
+    require!(amount > 0, ParadoxError::InsufficientFees);
+    require!(amount <= ctx.accounts.fee_vault.amount, ParadoxError::InsufficientFees);
+    
+    burn_from_fee_vault(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.fee_vault,
+        &ctx.accounts.harvest_authority.to_account_info(),
+        ctx.bumps.harvest_authority,
+        amount,
+    )?;
+    
+    let config = &mut ctx.accounts.token_config;
+    config.total_burned = config.total_burned
+        .checked_add(amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    msg!("🔥 Burned {} tokens from fee vault", amount);
+    msg!("   Total burned: {}", config.total_burned);
+    
+    emit!(FeesBurned {
+        mint: ctx.accounts.mint.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// SWEEP FEE DUST
+// =============================================================================
+//
+// Distribution leaves remainders below MIN_TRANSFER_AMOUNT in the fee vault.
+// Once the vault holds less than the dust threshold, admin can clear it in
+// one move: to the treasury if treasury accounts are passed, burned otherwise.
+//
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SweepFeeDust<'info> {
+    #[account(
+        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        constraint = fee_vault.key() == token_config.fee_vault @ ParadoxError::InvalidVault,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Harvest authority PDA (fee vault owner)
+    /// CHECK: PDA derived from mint - validated by seeds
+    #[account(
+        seeds = [HARVEST_AUTHORITY_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub harvest_authority: UncheckedAccount<'info>,
+    
+    /// Optional: send dust to treasury instead of burning
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, mint.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Option<Account<'info, DaoTreasuryVault>>,
+    
+    #[account(mut)]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn sweep_fee_dust_handler(ctx: Context<SweepFeeDust>) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
+    let amount = ctx.accounts.fee_vault.amount;
+    require!(amount > 0, ParadoxError::InsufficientFees);
+    require!(
+        amount < ctx.accounts.token_config.effective_dust_threshold(),
+        ParadoxError::NotDust
+    );
+    
+    let to_treasury = match (&mut ctx.accounts.treasury, &mut ctx.accounts.treasury_token_account) {
+        (Some(treasury), Some(treasury_token_account)) => {
+            require!(treasury.is_initialized(), ParadoxError::NotInitialized);
+            require!(
+                treasury_token_account.key() == treasury.token_account,
+                ParadoxError::InvalidVault
+            );
+            
+            let balance_before = treasury_token_account.amount;
+            transfer_from_fee_vault(
+                &ctx.accounts.token_program,
+                &ctx.accounts.mint,
+                &ctx.accounts.fee_vault,
+                treasury_token_account.to_account_info(),
+                &ctx.accounts.harvest_authority.to_account_info(),
+                ctx.bumps.harvest_authority,
+                amount,
+            )?;
+            
+            // Credit what actually arrived (Token-2022 transfer fee is withheld)
+            treasury_token_account.reload()?;
+            let received = treasury_token_account.amount.saturating_sub(balance_before);
+            treasury.balance = treasury.balance
+                .checked_add(received)
+                .ok_or(ParadoxError::MathOverflow)?;
+            true
+        }
+        (None, None) => {
+            burn_from_fee_vault(
+                &ctx.accounts.token_program,
+                &ctx.accounts.mint,
+                &ctx.accounts.fee_vault,
+                &ctx.accounts.harvest_authority.to_account_info(),
+                ctx.bumps.harvest_authority,
+                amount,
+            )?;
+            
+            let config = &mut ctx.accounts.token_config;
+            config.total_burned = config.total_burned
+                .checked_add(amount)
+                .ok_or(ParadoxError::MathOverflow)?;
+            false
+        }
+        _ => return err!(ParadoxError::InvalidVault),
+    };
+    
+    msg!("🧹 Swept {} dust tokens from fee vault ({})",
+         amount, if to_treasury { "to treasury" } else { "burned" });
+    
+    emit!(FeeDustSwept {
+        mint: ctx.accounts.mint.key(),
+        amount,
+        to_treasury,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// HARVEST AND DISTRIBUTE (atomic)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct HarvestAndDistribute<'info> {
+    /// Anyone can call (permissionless - destinations are fixed)
+    #[account(mut)]
+    pub harvester: Signer<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        constraint = fee_vault.key() == token_config.fee_vault @ ParadoxError::InvalidVault,
+        constraint = *fee_vault.to_account_info().owner == TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Harvest authority PDA (withdraw_withheld authority + fee vault owner)
+    /// CHECK: PDA derived from mint - validated by seeds
+    #[account(
+        seeds = [HARVEST_AUTHORITY_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub harvest_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LP_GROWTH_SEED, mint.key().as_ref()],
+        bump = lp_growth_manager.bump,
+    )]
+    pub lp_growth_manager: Account<'info, LpGrowthManager>,
+    
+    /// LP growth token account - must be the manager's fee accumulation account
+    #[account(
+        mut,
+        constraint = lp_growth_token_account.key() == lp_growth_manager.fee_accumulation_account @ ParadoxError::InvalidVault,
+    )]
+    pub lp_growth_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, mint.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.key() == treasury.token_account @ ParadoxError::InvalidVault,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Optional: harvester's token account for the keeper reward
+    #[account(
+        mut,
+        constraint = harvester_token_account.owner == harvester.key() @ ParadoxError::Unauthorized,
+    )]
+    pub harvester_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Optional: deployer-wide analytics (see init_program_stats)
+    #[account(
+        mut,
+        constraint = program_stats.deployer == token_config.deployer @ ParadoxError::Unauthorized,
+    )]
+    pub program_stats: Option<Account<'info, ProgramStats>>,
+    
+    /// Token program - must be Token-2022 (withdraw_withheld CPI)
+    #[account(address = TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Harvest withheld fees and distribute the fee vault in one transaction
+/// 
+/// Pass source accounts as remaining_accounts (same as harvest)
+pub fn harvest_and_distribute_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, HarvestAndDistribute<'info>>,
+) -> Result<()> {
+    log_compute!("harvest_and_distribute: entry");
+    
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    require!(!ctx.remaining_accounts.is_empty(), ParadoxError::NoFeesToHarvest);
+    
+    let mint_key = ctx.accounts.mint.key();
+    let authority_bump = ctx.bumps.harvest_authority;
+    let authority_info = ctx.accounts.harvest_authority.to_account_info();
+    
+    // 1. Harvest withheld fees into the fee vault
+    let balance_before = ctx.accounts.fee_vault.amount;
+    
+    log_compute!("harvest_and_distribute: before withdraw CPI");
+    withdraw_withheld_from_accounts(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        &authority_info,
+        authority_bump,
+        ctx.remaining_accounts,
+    )?;
+    
+    // 2. Read the new balance
+    ctx.accounts.fee_vault.reload()?;
+    let total_fees = ctx.accounts.fee_vault.amount;
+    let harvested = total_fees.saturating_sub(balance_before);
+    
+    if total_fees == 0 {
+        return Ok(());
+    }
+    
+    // 3. Keeper reward (only if configured and the harvester passed a token account)
+    let harvester_reward = match &ctx.accounts.harvester_token_account {
+        Some(harvester_token_account) => {
+            let reward = ctx.accounts.token_config.calculate_harvester_reward(harvested);
+            if reward > 0 {
+                transfer_from_fee_vault(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.mint,
+                    &ctx.accounts.fee_vault,
+                    harvester_token_account.to_account_info(),
+                    &authority_info,
+                    authority_bump,
+                    reward,
+                )?;
+            }
+            reward
+        }
+        None => 0,
+    };
+    let total_fees = total_fees
+        .checked_sub(harvester_reward)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    // 4. Split the rest per configured shares
+    let (to_lp, to_burn, to_treasury) = ctx.accounts.token_config.calculate_distribution(total_fees)?;
+    check_distribution_sum(total_fees, to_lp, to_burn, to_treasury)?;
+    let vault_before_split = ctx.accounts.fee_vault.amount
+        .checked_sub(harvester_reward)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    // 5. Route LP share to the growth manager
+    if to_lp > 0 {
+        transfer_from_fee_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.fee_vault,
+            ctx.accounts.lp_growth_token_account.to_account_info(),
+            &authority_info,
+            authority_bump,
+            to_lp,
+        )?;
+    }
+    
+    // 6. Burn
+    if to_burn > 0 {
+        burn_from_fee_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.fee_vault,
+            &authority_info,
+            authority_bump,
+            to_burn,
+        )?;
+    }
+    
+    // 7. Treasury
+    if to_treasury > 0 {
+        transfer_from_fee_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.fee_vault,
+            ctx.accounts.treasury_token_account.to_account_info(),
+            &authority_info,
+            authority_bump,
+            to_treasury,
+        )?;
+    }
+    
+    // 8. Reconcile: the vault must have dropped by exactly what was routed
+    ctx.accounts.fee_vault.reload()?;
+    require!(
+        vault_before_split.checked_sub(ctx.accounts.fee_vault.amount) == Some(total_fees),
+        ParadoxError::DistributionMismatch
+    );
+    
+    // 9. Internal moves paid the transfer fee - reclaim it from the destinations
+    let (lp_received, treasury_received) = reclaim_internal_transfer_fees(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.fee_vault,
+        ctx.accounts.lp_growth_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        &authority_info,
+        authority_bump,
+        to_lp,
+        to_treasury,
+    )?;
+    
+    // Update tracking (checked arithmetic) - credit what actually arrived
+    let manager = &mut ctx.accounts.lp_growth_manager;
+    manager.accumulated_token_fees = manager.accumulated_token_fees
+        .checked_add(lp_received)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    if lp_received > 0 {
+        emit!(LpGrowthFeesAccumulated {
+            mint: manager.mint,
+            added: lp_received,
+            new_total: manager.accumulated_token_fees,
+        });
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.balance = treasury.balance
+        .checked_add(treasury_received)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    let config = &mut ctx.accounts.token_config;
+    config.total_fees_collected = config.total_fees_collected
+        .checked_add(harvested)
+        .ok_or(ParadoxError::MathOverflow)?;
+    config.total_fees_distributed = config.total_fees_distributed
+        .checked_add(total_fees)
+        .ok_or(ParadoxError::MathOverflow)?;
+    config.total_burned = config.total_burned
+        .checked_add(to_burn)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    if let Some(stats) = &mut ctx.accounts.program_stats {
+        stats.record_fees_harvested(harvested);
+        stats.record_burn(to_burn);
+    }
+    
+    msg!("✅ Harvested {} and distributed {}: LP={}, Burn={}, Treasury={}, Keeper={}",
+         harvested, total_fees, to_lp, to_burn, to_treasury, harvester_reward);
+    
+    emit!(FeesHarvested {
+        mint: mint_key,
+        amount: harvested,
+        harvested_by: ctx.accounts.harvester.key(),
+        destination: ctx.accounts.fee_vault.key(),
+        harvester_reward,
+    });
+    
+    emit!(FeesDistributed {
+        total_fees,
+        to_lp,
+        burned: to_burn,
+        to_treasury,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// HARVEST TO GROWTH (atomic, splits this harvest only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct HarvestToGrowth<'info> {
+    /// Anyone can call (permissionless - destination is fixed)
+    #[account(mut)]
+    pub harvester: Signer<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        constraint = fee_vault.key() == token_config.fee_vault @ ParadoxError::InvalidVault,
+        constraint = *fee_vault.to_account_info().owner == TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022,
+    )]
+    pub fee_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Harvest authority PDA (withdraw_withheld authority + fee vault owner)
+    /// CHECK: PDA derived from mint - validated by seeds
+    #[account(
+        seeds = [HARVEST_AUTHORITY_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub harvest_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LP_GROWTH_SEED, mint.key().as_ref()],
+        bump = lp_growth_manager.bump,
+    )]
+    pub lp_growth_manager: Account<'info, LpGrowthManager>,
+    
+    /// LP growth token account - must be the manager's fee accumulation account
+    #[account(
+        mut,
+        constraint = lp_growth_token_account.key() == lp_growth_manager.fee_accumulation_account @ ParadoxError::InvalidVault,
+    )]
+    pub lp_growth_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, mint.key().as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.key() == treasury.token_account @ ParadoxError::InvalidVault,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Optional: deployer-wide analytics (see init_program_stats)
+    #[account(
+        mut,
+        constraint = program_stats.deployer == token_config.deployer @ ParadoxError::Unauthorized,
+    )]
+    pub program_stats: Option<Account<'info, ProgramStats>>,
+    
+    /// Token program - must be Token-2022 (withdraw_withheld CPI)
+    #[account(address = TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Harvest withheld fees and split only this harvest: LP share to growth,
+/// burn share burned, treasury share to the treasury
+/// 
+/// All three legs settle here. Leaving burn/treasury in the vault for a later
+/// distribute would re-split them by the configured shares, sending part of
+/// them to LP again.
+/// Pass source accounts as remaining_accounts (same as harvest)
+pub fn harvest_to_growth_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, HarvestToGrowth<'info>>,
+) -> Result<()> {
+    log_compute!("harvest_to_growth: entry");
+    
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    require!(!ctx.remaining_accounts.is_empty(), ParadoxError::NoFeesToHarvest);
+    
+    let mint_key = ctx.accounts.mint.key();
+    let authority_bump = ctx.bumps.harvest_authority;
+    let authority_info = ctx.accounts.harvest_authority.to_account_info();
+    
+    // 1. Harvest withheld fees into the fee vault
+    let balance_before = ctx.accounts.fee_vault.amount;
+    
+    log_compute!("harvest_to_growth: before withdraw CPI");
+    withdraw_withheld_from_accounts(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        &authority_info,
+        authority_bump,
+        ctx.remaining_accounts,
+    )?;
+    
+    ctx.accounts.fee_vault.reload()?;
+    let harvested = ctx.accounts.fee_vault.amount.saturating_sub(balance_before);
+    
+    if harvested == 0 {
+        return Ok(());
+    }
+    
+    // 2. Split this harvest only (anything already in the vault is left for distribute)
+    let (to_lp, to_burn, to_treasury) = ctx.accounts.token_config.calculate_distribution(harvested)?;
+    check_distribution_sum(harvested, to_lp, to_burn, to_treasury)?;
+    let vault_before_split = ctx.accounts.fee_vault.amount;
+    
+    // 3. LP share to growth
+    if to_lp > 0 {
+        transfer_from_fee_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.fee_vault,
+            ctx.accounts.lp_growth_token_account.to_account_info(),
+            &authority_info,
+            authority_bump,
+            to_lp,
+        )?;
+    }
+    
+    // 4. Burn
+    if to_burn > 0 {
+        burn_from_fee_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.fee_vault,
+            &authority_info,
+            authority_bump,
+            to_burn,
+        )?;
+    }
+    
+    // 5. Treasury
+    if to_treasury > 0 {
+        transfer_from_fee_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.mint,
+            &ctx.accounts.fee_vault,
+            ctx.accounts.treasury_token_account.to_account_info(),
+            &authority_info,
+            authority_bump,
+            to_treasury,
+        )?;
+    }
+    
+    // 6. Reconcile: the vault must have dropped by exactly the harvest
+    ctx.accounts.fee_vault.reload()?;
+    require!(
+        vault_before_split.checked_sub(ctx.accounts.fee_vault.amount) == Some(harvested),
+        ParadoxError::DistributionMismatch
+    );
+    
+    // 7. Reclaim the internal transfer fees (see module doc)
+    let (lp_received, treasury_received) = reclaim_internal_transfer_fees(
+        &ctx.accounts.token_program,
+        &ctx.accounts.mint,
+        &ctx.accounts.fee_vault,
+        ctx.accounts.lp_growth_token_account.to_account_info(),
+        ctx.accounts.treasury_token_account.to_account_info(),
+        &authority_info,
+        authority_bump,
+        to_lp,
+        to_treasury,
+    )?;
+    
+    // 8. Credit growth manager and treasury with what actually arrived
+    let manager = &mut ctx.accounts.lp_growth_manager;
+    manager.accumulated_token_fees = manager.accumulated_token_fees
+        .checked_add(lp_received)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    if lp_received > 0 {
+        emit!(LpGrowthFeesAccumulated {
+            mint: manager.mint,
+            added: lp_received,
+            new_total: manager.accumulated_token_fees,
+        });
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.balance = treasury.balance
+        .checked_add(treasury_received)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    let config = &mut ctx.accounts.token_config;
+    config.total_fees_collected = config.total_fees_collected
+        .checked_add(harvested)
+        .ok_or(ParadoxError::MathOverflow)?;
+    config.total_fees_distributed = config.total_fees_distributed
+        .checked_add(harvested)
+        .ok_or(ParadoxError::MathOverflow)?;
+    config.total_burned = config.total_burned
+        .checked_add(to_burn)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    if let Some(stats) = &mut ctx.accounts.program_stats {
+        stats.record_fees_harvested(harvested);
+        stats.record_burn(to_burn);
+    }
+    
+    msg!("✅ Harvested {} and split it: LP={} ({} received), Burn={}, Treasury={}",
+         harvested, to_lp, lp_received, to_burn, to_treasury);
+    
+    emit!(FeesHarvested {
+        mint: mint_key,
+        amount: harvested,
+        harvested_by: ctx.accounts.harvester.key(),
+        destination: ctx.accounts.fee_vault.key(),
+        harvester_reward: 0,
+    });
+    
+    emit!(FeesDistributed {
+        total_fees: harvested,
+        to_lp,
+        burned: to_burn,
+        to_treasury,
+    });
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee_mode::tests::mint_with_transfer_fee;
+    
+    #[test]
+    fn internal_transfer_receipts_are_net_of_transfer_fee() {
+        let mut config = TokenConfig::try_deserialize_unchecked(&mut &vec![0u8; TokenConfig::LEN][..]).unwrap();
+        config.lp_share_bps = 7_000;
+        config.burn_share_bps = 1_000;
+        
+        let (to_lp, to_burn, to_treasury) = config.calculate_distribution(100_000).unwrap();
+        assert_eq!((to_lp, to_burn, to_treasury), (70_000, 10_000, 20_000));
+        
+        // 1% uncapped: both destinations are short by their own fee, which gets reclaimed
+        let mut data = mint_with_transfer_fee(100, u64::MAX);
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 0;
+        let mint = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(
+            internal_transfer_receipts_at(&mint, to_lp, to_treasury, 0).unwrap(),
+            (69_300, 19_800)
+        );
+        
+        // Fee capped at 50 per transfer
+        let mut data = mint_with_transfer_fee(100, 50);
+        let mut lamports = 0;
+        let mint = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(
+            internal_transfer_receipts_at(&mint, to_lp, to_treasury, 0).unwrap(),
+            (69_950, 19_950)
+        );
+        
+        // Nothing routed, nothing received
+        assert_eq!(internal_transfer_receipts_at(&mint, 0, 0, 0).unwrap(), (0, 0));
+    }
+}
@@ -4,19 +4,26 @@
  * Collects withheld Token-2022 transfer fees and sends them to the fee vault.
  * Uses actual Token-2022 CPI calls - no placeholders.
  * 
+ * The fee vault MUST be a Token-2022 account: withheld fees are tokens of the
+ * Token-2022 mint, which a legacy SPL Token account cannot hold. Enforced by
+ * constraints so misconfiguration fails with FeeVaultNotToken2022 instead of
+ * an opaque CPI error.
+ * 
  * Made by LabsX402 for Solana
  * https://x.com/LabsX402
  */
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_2022::ID as TOKEN_2022_PROGRAM_ID;
 use anchor_spl::token_interface::{
     TokenInterface, TokenAccount, Mint, 
     Interface, InterfaceAccount,
 };
 
 use crate::{
-    state::TokenConfig,
+    state::{TokenConfig, ProgramStats},
+    fee_mode::mint_withheld_amount,
     ParadoxError,
     TOKEN_CONFIG_SEED,
     FeesHarvested,
@@ -29,6 +36,58 @@ pub const HARVEST_AUTHORITY_SEED: &[u8] = b"harvest_authority";
 // HARVEST WITHHELD FEES FROM ACCOUNTS
 // =============================================================================
 
+/// Withdraw withheld Token-2022 fees from source accounts into the fee vault
+/// Signed by the harvest authority PDA (withdraw_withheld authority)
+pub fn withdraw_withheld_from_accounts<'info>(
+    token_program: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    fee_vault: &AccountInfo<'info>,
+    harvest_authority: &AccountInfo<'info>,
+    harvest_authority_bump: u8,
+    sources: &[AccountInfo<'info>],
+) -> Result<()> {
+    // Build the withdraw_withheld_tokens_from_accounts instruction
+    // This collects fees from multiple accounts in one transaction
+    let source_pubkeys: Vec<&Pubkey> = sources
+        .iter()
+        .map(|acc| acc.key)
+        .collect();
+    
+    // Create the instruction using spl_token_2022
+    let ix = spl_token_2022::instruction::withdraw_withheld_tokens_from_accounts(
+        token_program.key,
+        mint.key,
+        fee_vault.key,
+        harvest_authority.key,
+        &[], // No additional signers (PDA signs)
+        &source_pubkeys,
+    )?;
+    
+    // Build account infos for CPI
+    let mut account_infos = vec![
+        mint.clone(),
+        fee_vault.clone(),
+        harvest_authority.clone(),
+    ];
+    
+    // Add source accounts
+    for acc in sources.iter() {
+        account_infos.push(acc.clone());
+    }
+    
+    // PDA signer seeds
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        HARVEST_AUTHORITY_SEED,
+        mint.key.as_ref(),
+        &[harvest_authority_bump],
+    ]];
+    
+    // Execute CPI
+    invoke_signed(&ix, &account_infos, signer_seeds)?;
+    
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct HarvestWithheldFees<'info> {
     /// Anyone can call harvest (permissionless to prevent griefing)
@@ -39,6 +98,7 @@ pub struct HarvestWithheldFees<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
+        mut,
         seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
         bump = token_config.bump,
     )]
@@ -48,6 +108,7 @@ pub struct HarvestWithheldFees<'info> {
     #[account(
         mut,
         constraint = fee_vault.key() == token_config.fee_vault @ ParadoxError::InvalidVault,
+        constraint = *fee_vault.to_account_info().owner == TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022,
     )]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     
@@ -59,7 +120,15 @@ pub struct HarvestWithheldFees<'info> {
     )]
     pub harvest_authority: UncheckedAccount<'info>,
     
+    /// Optional: deployer-wide analytics (see init_program_stats)
+    #[account(
+        mut,
+        constraint = program_stats.deployer == token_config.deployer @ ParadoxError::Unauthorized,
+    )]
+    pub program_stats: Option<Account<'info, ProgramStats>>,
+    
     /// Token program - must be Token-2022 for transfer fee extension
+    #[account(address = TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022)]
     pub token_program: Interface<'info, TokenInterface>,
 }
 
@@ -69,9 +138,12 @@ pub struct HarvestWithheldFees<'info> {
 /// Fees go to the protocol's fee_vault, not to the caller.
 /// 
 /// Pass source accounts as remaining_accounts (up to 10)
-pub fn harvest_withheld_fees_handler(ctx: Context<HarvestWithheldFees>) -> Result<u64> {
+pub fn harvest_withheld_fees_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, HarvestWithheldFees<'info>>,
+) -> Result<u64> {
+    log_compute!("harvest_withheld_fees: entry");
+    
     let mint_key = ctx.accounts.mint.key();
-    let token_program_id = ctx.accounts.token_program.key();
     
     // Get source accounts from remaining_accounts
     let source_account_infos: Vec<AccountInfo> = ctx.remaining_accounts.to_vec();
@@ -80,57 +152,39 @@ pub fn harvest_withheld_fees_handler(ctx: Context<HarvestWithheldFees>) -> Resul
         return Err(error!(ParadoxError::NoFeesToHarvest));
     }
     
-    // Build the withdraw_withheld_tokens_from_accounts instruction
-    // This collects fees from multiple accounts in one transaction
-    let source_pubkeys: Vec<&Pubkey> = source_account_infos
-        .iter()
-        .map(|acc| acc.key)
-        .collect();
+    let balance_before = ctx.accounts.fee_vault.amount;
     
-    // Create the instruction using spl_token_2022
-    let ix = spl_token_2022::instruction::withdraw_withheld_tokens_from_accounts(
-        &token_program_id,
-        &mint_key,
-        &ctx.accounts.fee_vault.key(),
-        &ctx.accounts.harvest_authority.key(),
-        &[], // No additional signers (PDA signs)
-        &source_pubkeys,
+    log_compute!("harvest_withheld_fees: before withdraw CPI");
+    withdraw_withheld_from_accounts(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.fee_vault.to_account_info(),
+        &ctx.accounts.harvest_authority.to_account_info(),
+        ctx.bumps.harvest_authority,
+        &source_account_infos,
     )?;
     
-    // Build account infos for CPI
-    let mut account_infos = vec![
-        ctx.accounts.mint.to_account_info(),
-        ctx.accounts.fee_vault.to_account_info(),
-        ctx.accounts.harvest_authority.to_account_info(),
-    ];
-    
-    // Add source accounts
-    for acc in source_account_infos.iter() {
-        account_infos.push(acc.clone());
-    }
-    
-    // PDA signer seeds
-    let bump = ctx.bumps.harvest_authority;
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        HARVEST_AUTHORITY_SEED,
-        mint_key.as_ref(),
-        &[bump],
-    ]];
+    // Harvested amount = fee_vault balance change
+    ctx.accounts.fee_vault.reload()?;
+    let harvested_amount = ctx.accounts.fee_vault.amount.saturating_sub(balance_before);
     
-    // Execute CPI
-    invoke_signed(&ix, &account_infos, signer_seeds)?;
+    let config = &mut ctx.accounts.token_config;
+    config.total_fees_collected = config.total_fees_collected
+        .checked_add(harvested_amount)
+        .ok_or(ParadoxError::MathOverflow)?;
     
-    // Get harvested amount from fee_vault balance change
-    // Note: In production, compare before/after balances for exact amount
-    let harvested_amount = source_pubkeys.len() as u64; // Placeholder for actual amount
+    if let Some(stats) = &mut ctx.accounts.program_stats {
+        stats.record_fees_harvested(harvested_amount);
+    }
     
-    msg!("✅ Harvested fees from {} accounts to vault", source_pubkeys.len());
+    msg!("✅ Harvested fees from {} accounts to vault", source_account_infos.len());
     
     emit!(FeesHarvested {
         mint: mint_key,
         amount: harvested_amount,
         harvested_by: ctx.accounts.harvester.key(),
         destination: ctx.accounts.fee_vault.key(),
+        harvester_reward: 0,
     });
     
     Ok(harvested_amount)
@@ -150,6 +204,7 @@ pub struct HarvestMintFees<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
     
     #[account(
+        mut,
         seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
         bump = token_config.bump,
     )]
@@ -159,6 +214,7 @@ pub struct HarvestMintFees<'info> {
     #[account(
         mut,
         constraint = fee_vault.key() == token_config.fee_vault @ ParadoxError::InvalidVault,
+        constraint = *fee_vault.to_account_info().owner == TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022,
     )]
     pub fee_vault: InterfaceAccount<'info, TokenAccount>,
     
@@ -170,11 +226,22 @@ pub struct HarvestMintFees<'info> {
     )]
     pub harvest_authority: UncheckedAccount<'info>,
     
+    /// Optional: deployer-wide analytics (see init_program_stats)
+    #[account(
+        mut,
+        constraint = program_stats.deployer == token_config.deployer @ ParadoxError::Unauthorized,
+    )]
+    pub program_stats: Option<Account<'info, ProgramStats>>,
+    
+    /// Token program - must be Token-2022 for transfer fee extension
+    #[account(address = TOKEN_2022_PROGRAM_ID @ ParadoxError::FeeVaultNotToken2022)]
     pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Harvest withheld fees accumulated on the mint itself
 pub fn harvest_mint_fees_handler(ctx: Context<HarvestMintFees>) -> Result<u64> {
+    log_compute!("harvest_mint_fees: entry");
+    
     let mint_key = ctx.accounts.mint.key();
     let token_program_id = ctx.accounts.token_program.key();
     
@@ -202,13 +269,30 @@ pub fn harvest_mint_fees_handler(ctx: Context<HarvestMintFees>) -> Result<u64> {
         &[bump],
     ]];
     
+    let balance_before = ctx.accounts.fee_vault.amount;
+    let withheld_before = mint_withheld_amount(&ctx.accounts.mint.to_account_info())?;
+    
     // Execute CPI
+    log_compute!("harvest_mint_fees: before withdraw CPI");
     invoke_signed(&ix, &account_infos, signer_seeds)?;
     
-    msg!("✅ Harvested fees from mint to vault");
+    // Harvested amount = fee_vault balance change
+    ctx.accounts.fee_vault.reload()?;
+    let harvested_amount = ctx.accounts.fee_vault.amount.saturating_sub(balance_before);
+    
+    // The whole mint-held amount must land in the vault
+    require!(harvested_amount == withheld_before, ParadoxError::HarvestMismatch);
+    
+    msg!("✅ Harvested {} fees from mint to vault", harvested_amount);
     
-    // Get actual harvested amount from the transfer
-    let harvested_amount: u64 = 0; // Would need to track balance change
+    let config = &mut ctx.accounts.token_config;
+    config.total_fees_collected = config.total_fees_collected
+        .checked_add(harvested_amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    if let Some(stats) = &mut ctx.accounts.program_stats {
+        stats.record_fees_harvested(harvested_amount);
+    }
     
     if harvested_amount > 0 {
         emit!(FeesHarvested {
@@ -216,6 +300,7 @@ pub fn harvest_mint_fees_handler(ctx: Context<HarvestMintFees>) -> Result<u64> {
             amount: harvested_amount,
             harvested_by: ctx.accounts.harvester.key(),
             destination: ctx.accounts.fee_vault.key(),
+            harvester_reward: 0,
         });
     }
     
@@ -9,11 +9,15 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, InterfaceAccount};
 
 use crate::{
-    state::TokenConfig,
+    state::{TokenConfig, ProgramStats},
     ParadoxError,
     TOKEN_CONFIG_SEED,
     MIN_TRANSFER_FEE_BPS,
     MAX_TRANSFER_FEE_BPS,
+    PROTOCOL_MAX_FEE_BPS,
+    DEFAULT_FEE_EXECUTION_WINDOW_HOURS,
+    MIN_FEE_EXECUTION_WINDOW_HOURS,
+    CURRENT_ACCOUNT_VERSION,
     TokenConfigInitialized,
 };
 
@@ -36,6 +40,13 @@ pub struct InitTokenConfig<'info> {
     /// CHECK: Fee vault (created separately)
     pub fee_vault: UncheckedAccount<'info>,
     
+    /// Optional: deployer-wide analytics (see init_program_stats)
+    #[account(
+        mut,
+        constraint = program_stats.deployer == admin.key() @ ParadoxError::Unauthorized,
+    )]
+    pub program_stats: Option<Account<'info, ProgramStats>>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -45,10 +56,31 @@ pub fn handler(
     lp_share_bps: u16,
     burn_share_bps: u16,
     treasury_share_bps: u16,
+    min_fee_bps: Option<u16>, // None = MIN_TRANSFER_FEE_BPS
+    max_fee_bps: Option<u16>, // None = MAX_TRANSFER_FEE_BPS
+    fee_execution_window_hours: Option<u16>, // None = DEFAULT_FEE_EXECUTION_WINDOW_HOURS
+    governance: Pubkey,
 ) -> Result<()> {
+    let min_fee_bps = min_fee_bps.unwrap_or(MIN_TRANSFER_FEE_BPS);
+    let max_fee_bps = max_fee_bps.unwrap_or(MAX_TRANSFER_FEE_BPS);
+    let fee_execution_window_hours = fee_execution_window_hours
+        .unwrap_or(DEFAULT_FEE_EXECUTION_WINDOW_HOURS);
+    
+    // Wait and execution window are separate: the window only needs a sane floor
+    require!(
+        fee_execution_window_hours >= MIN_FEE_EXECUTION_WINDOW_HOURS,
+        ParadoxError::InvalidFeeExecutionWindow
+    );
+    
+    // Per-config bounds within the hard protocol cap (max 0 is reserved for "unset")
+    require!(
+        max_fee_bps > 0 && max_fee_bps <= PROTOCOL_MAX_FEE_BPS && min_fee_bps <= max_fee_bps,
+        ParadoxError::InvalidFeeBounds
+    );
+    
     // Validate transfer fee
     require!(
-        transfer_fee_bps >= MIN_TRANSFER_FEE_BPS && transfer_fee_bps <= MAX_TRANSFER_FEE_BPS,
+        transfer_fee_bps >= min_fee_bps && transfer_fee_bps <= max_fee_bps,
         ParadoxError::InvalidTransferFee
     );
     
@@ -56,12 +88,18 @@ pub fn handler(
     let total_shares = lp_share_bps as u32 + burn_share_bps as u32 + treasury_share_bps as u32;
     require!(total_shares == 10_000, ParadoxError::InvalidFeeShares);
     
+    // Governance gates must not collapse into the admin key
+    require!(
+        governance != Pubkey::default() && governance != ctx.accounts.admin.key(),
+        ParadoxError::InvalidGovernance
+    );
+    
     let config = &mut ctx.accounts.token_config;
     let clock = Clock::get()?;
     
     config.mint = ctx.accounts.mint.key();
     config.admin = ctx.accounts.admin.key();
-    config.governance = ctx.accounts.admin.key(); // Initially same as admin
+    config.governance = governance;
     config.transfer_fee_bps = transfer_fee_bps;
     config.lp_share_bps = lp_share_bps;
     config.burn_share_bps = burn_share_bps;
@@ -76,6 +114,18 @@ pub fn handler(
     config.pending_fee_activate_time = 0;
     config.pending_fee_cancel_time = 0;
     config.bump = ctx.bumps.token_config;
+    config.total_burned = 0;
+    config.min_fee_bps = min_fee_bps;
+    config.refresh_min_transfer_amount();
+    config.max_fee_bps = max_fee_bps;
+    config.fee_execution_window_hours = fee_execution_window_hours;
+    config.deployer = ctx.accounts.admin.key();
+    config.is_initialized = true;
+    config.version = CURRENT_ACCOUNT_VERSION;
+    
+    if let Some(stats) = &mut ctx.accounts.program_stats {
+        stats.record_launch();
+    }
     
     emit!(TokenConfigInitialized {
         mint: config.mint,
@@ -83,6 +133,8 @@ pub fn handler(
         lp_share_bps,
         burn_share_bps,
         treasury_share_bps,
+        min_fee_bps,
+        max_fee_bps,
     });
     
     Ok(())
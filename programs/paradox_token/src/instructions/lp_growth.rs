@@ -6,20 +6,29 @@
  */
 
 use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
 use anchor_spl::token_interface::{
     TokenInterface, TokenAccount, Mint,
+    CloseAccount, close_account,
+    SetAuthority, set_authority,
     InterfaceAccount, Interface,
+    spl_token_2022::instruction::AuthorityType,
 };
 
 use crate::{
-    state::{LpGrowthManager, TokenConfig},
+    state::{LpGrowthManager, LpGrowthQuote, TokenConfig, ProgramStats, DEFAULT_MAX_MINT_RATIO_BPS, MIN_GROWTH_COOLDOWN_SECONDS},
+    lp_value::{lp_value_from_reserves, price_from_reserves, lp_tokens_for_deposit},
     ParadoxError,
     LP_GROWTH_SEED,
     TOKEN_CONFIG_SEED,
+    CURRENT_ACCOUNT_VERSION,
     LpGrowthInitialized,
+    MintAuthorityAssignedToGrowth,
     LpGrowthExecuted,
     LpGrowthLocked,
     LpGrowthUnlocked,
+    LpGrowthPoolsUpdated,
+    FeesSwappedToSol,
 };
 
 // =============================================================================
@@ -45,7 +54,7 @@ pub struct InitLpGrowth<'info> {
     /// CHECK: LP pool address (validated by caller)
     pub lp_pool: UncheckedAccount<'info>,
     
-    /// CHECK: Fee accumulation account
+    /// CHECK: Token account where LP share fees accumulate
     pub fee_accumulation_account: UncheckedAccount<'info>,
     
     pub system_program: Program<'info, System>,
@@ -55,7 +64,12 @@ pub fn init_handler(
     ctx: Context<InitLpGrowth>,
     min_fee_threshold: u64,
     cooldown_seconds: i64,
+    max_mint_ratio_bps: Option<u16>,
 ) -> Result<()> {
+    let max_mint_ratio_bps = max_mint_ratio_bps.unwrap_or(DEFAULT_MAX_MINT_RATIO_BPS);
+    require!(max_mint_ratio_bps <= 10_000, ParadoxError::InvalidThresholds);
+    require!(cooldown_seconds >= MIN_GROWTH_COOLDOWN_SECONDS, ParadoxError::InvalidGrowthCooldown);
+    
     let manager = &mut ctx.accounts.lp_growth_manager;
     
     manager.mint = ctx.accounts.mint.key();
@@ -67,9 +81,17 @@ pub fn init_handler(
     manager.last_growth_time = 0;
     manager.total_sol_added = 0;
     manager.total_tokens_minted = 0;
-    manager.accumulated_fees = 0;
+    manager.accumulated_sol = 0;
+    manager.accumulated_token_fees = 0;
+    manager.last_known_price = 0;
+    manager.max_mint_ratio_bps = max_mint_ratio_bps;
+    manager.secondary_lp_pool = Pubkey::default();
+    manager.primary_pool_weight_bps = 10_000;
+    manager.secondary_pool_weight_bps = 0;
     manager.is_locked = false;
     manager.bump = ctx.bumps.lp_growth_manager;
+    manager.is_initialized = true;
+    manager.version = CURRENT_ACCOUNT_VERSION;
     
     emit!(LpGrowthInitialized {
         mint: manager.mint,
@@ -80,6 +102,78 @@ pub fn init_handler(
     Ok(())
 }
 
+// =============================================================================
+// ASSIGN MINT AUTHORITY TO GROWTH PDA
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct AssignMintAuthorityToGrowth<'info> {
+    /// Current mint authority (checked in handler)
+    pub mint_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [LP_GROWTH_SEED, mint.key().as_ref()],
+        bump = lp_growth_manager.bump,
+    )]
+    pub lp_growth_manager: Account<'info, LpGrowthManager>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Hand the mint authority to the lp_growth_manager PDA (required by execute_lp_growth)
+pub fn assign_mint_authority_handler(ctx: Context<AssignMintAuthorityToGrowth>) -> Result<()> {
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    
+    let old_authority = ctx.accounts.mint_authority.key();
+    require!(
+        Option::<Pubkey>::from(ctx.accounts.mint.mint_authority) == Some(old_authority),
+        ParadoxError::Unauthorized
+    );
+    
+    let growth_pda = ctx.accounts.lp_growth_manager.key();
+    
+    set_authority(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        AuthorityType::MintTokens,
+        Some(growth_pda),
+    )?;
+    
+    msg!("Mint authority: {} → {} (LP growth PDA)", old_authority, growth_pda);
+    
+    emit!(MintAuthorityAssignedToGrowth {
+        mint: ctx.accounts.mint.key(),
+        old_authority,
+        new_authority: growth_pda,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// POOL READ
+// =============================================================================
+
+/// Pool reserves as (sol_reserve, token_reserve, lp_supply)
+/// Shared by execute_lp_growth and quote_lp_growth so both see the same pool
+fn read_pool_reserves(_manager: &LpGrowthManager) -> (u64, u64, u64) {
+    // DEV: Read the pool at manager.lp_pool (same source as calculate_tokens_to_mint).
+    // Empty reserves = unknown price, circuit breaker is skipped.
+    let sol_reserve: u64 = 0; // Replace with actual pool SOL reserve
+    let token_reserve: u64 = 0; // Replace with actual pool token reserve
+    let lp_supply: u64 = 0; // Replace with actual LP mint supply
+    
+    (sol_reserve, token_reserve, lp_supply)
+}
+
 // =============================================================================
 // EXECUTE LP GROWTH
 // =============================================================================
@@ -96,7 +190,11 @@ pub struct ExecuteLpGrowth<'info> {
     )]
     pub lp_growth_manager: Account<'info, LpGrowthManager>,
     
-    #[account(mut)]
+    /// Mint authority must be the lp_growth_manager PDA (see assign_mint_authority_to_growth)
+    #[account(
+        mut,
+        constraint = mint.key() == lp_growth_manager.mint @ ParadoxError::InvalidAccountType,
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
     
     // =========================================================================
@@ -114,9 +212,8 @@ pub struct ExecuteLpGrowth<'info> {
     //   pub whirlpool: Account<'info, Whirlpool>,
     //   pub orca_program: Program<'info, OrcaWhirlpool>,
     //
-    // For Meteora:
-    //   pub dlmm_pool: Account<'info, DlmmPool>,
-    //   pub meteora_program: Program<'info, MeteoraDlmm>,
+    // For Meteora DLMM: build with `--features meteora` and use
+    //   execute_lp_growth_meteora (see lp_growth_meteora.rs)
     //
     // =========================================================================
     
@@ -124,11 +221,35 @@ pub struct ExecuteLpGrowth<'info> {
     #[account(mut)]
     pub fee_accumulation_account: UncheckedAccount<'info>,
     
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    /// Optional: deployer-wide analytics (see init_program_stats)
+    #[account(
+        mut,
+        constraint = program_stats.deployer == token_config.deployer @ ParadoxError::Unauthorized,
+    )]
+    pub program_stats: Option<Account<'info, ProgramStats>>,
+    
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn execute_handler(ctx: Context<ExecuteLpGrowth>) -> Result<()> {
+    log_compute!("execute_lp_growth: entry");
+    
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    
+    // Growth mints new tokens - fail clearly here instead of inside the mint CPI
+    require!(
+        Option::<Pubkey>::from(ctx.accounts.mint.mint_authority)
+            == Some(ctx.accounts.lp_growth_manager.key()),
+        ParadoxError::MintAuthorityMismatch
+    );
+    
     let manager = &mut ctx.accounts.lp_growth_manager;
     let clock = Clock::get()?;
     
@@ -137,7 +258,21 @@ pub fn execute_handler(ctx: Context<ExecuteLpGrowth>) -> Result<()> {
     require!(manager.can_execute_growth(clock.unix_timestamp), ParadoxError::CooldownNotPassed);
     require!(manager.has_enough_fees(), ParadoxError::InsufficientFees);
     
-    let sol_to_add = manager.accumulated_fees;
+    let sol_to_add = manager.accumulated_sol;
+    
+    let (sol_reserve, token_reserve, _lp_supply) = read_pool_reserves(manager);
+    let current_price = price_from_reserves(sol_reserve, token_reserve)?;
+    
+    // Circuit breaker: don't mint into a crash
+    require!(
+        !manager.price_dropped_too_far(current_price),
+        ParadoxError::GrowthPausedByPriceDrop
+    );
+    
+    // Stamp the cooldown before any CPI so nothing re-entering this block can grow again
+    manager.last_growth_time = clock.unix_timestamp;
+    
+    log_compute!("execute_lp_growth: before mint/add-liquidity CPI");
     
     // =========================================================================
     // DEV NOTE: Implement your LP growth logic here
@@ -145,9 +280,9 @@ pub fn execute_handler(ctx: Context<ExecuteLpGrowth>) -> Result<()> {
     //
     // This is where you add the actual LP growth implementation.
     // 
-    // Steps:
+    // Steps (per pool in manager.pool_allocations(sol_to_add)):
     // 1. Get current pool price
-    // 2. Calculate tokens to mint to match SOL
+    // 2. Calculate tokens to mint to match that pool's SOL
     // 3. Mint tokens (requires mint authority on this PDA)
     // 4. Add liquidity to pool
     //
@@ -176,25 +311,259 @@ pub fn execute_handler(ctx: Context<ExecuteLpGrowth>) -> Result<()> {
     // =========================================================================
     
     // Placeholder: Just log that LP growth would happen
-    msg!("LP Growth: Would add {} lamports to LP", sol_to_add);
+    for (pool, pool_sol) in manager.pool_allocations(sol_to_add) {
+        if pool_sol > 0 {
+            msg!("LP Growth: Would add {} lamports to pool {}", pool_sol, pool);
+        }
+    }
     
     let tokens_minted = 0; // Replace with actual minted amount
     
+    // Post-growth value, priced at the pre-growth spot price
+    let new_lp_value = lp_value_from_reserves(
+        sol_reserve.checked_add(sol_to_add).ok_or(ParadoxError::MathOverflow)?,
+        token_reserve.checked_add(tokens_minted).ok_or(ParadoxError::MathOverflow)?,
+        current_price,
+    )?;
+    
     // Update state (checked arithmetic)
-    manager.accumulated_fees = 0;
-    manager.last_growth_time = clock.unix_timestamp;
-    manager.total_sol_added = manager.total_sol_added
-        .checked_add(sol_to_add)
-        .ok_or(ParadoxError::MathOverflow)?;
-    manager.total_tokens_minted = manager.total_tokens_minted
-        .checked_add(tokens_minted)
-        .ok_or(ParadoxError::MathOverflow)?;
+    manager.record_growth(sol_to_add, tokens_minted, current_price, clock.unix_timestamp)?;
+    
+    if let Some(stats) = &mut ctx.accounts.program_stats {
+        stats.record_lp_growth(sol_to_add);
+    }
     
     emit!(LpGrowthExecuted {
         mint: manager.mint,
         sol_added: sol_to_add,
         tokens_minted,
-        new_lp_value: 0, // Replace with actual LP value
+        new_lp_value,
+        pending_token_fees: manager.accumulated_token_fees,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// QUOTE LP GROWTH (read-only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct QuoteLpGrowth<'info> {
+    #[account(
+        seeds = [LP_GROWTH_SEED, lp_growth_manager.mint.as_ref()],
+        bump = lp_growth_manager.bump,
+    )]
+    pub lp_growth_manager: Account<'info, LpGrowthManager>,
+}
+
+/// Simulate the next execute_lp_growth without minting or touching state
+/// Keepers use this to set slippage bounds for the real call
+pub fn quote_handler(ctx: Context<QuoteLpGrowth>) -> Result<LpGrowthQuote> {
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    
+    let manager = &ctx.accounts.lp_growth_manager;
+    let sol_to_add = manager.accumulated_sol;
+    
+    let (sol_reserve, token_reserve, lp_supply) = read_pool_reserves(manager);
+    let current_price = price_from_reserves(sol_reserve, token_reserve)?;
+    
+    let tokens_to_mint = manager.calculate_tokens_to_mint(sol_to_add, current_price)?;
+    let estimated_lp_out = lp_tokens_for_deposit(
+        sol_to_add,
+        tokens_to_mint,
+        sol_reserve,
+        token_reserve,
+        lp_supply,
+    )?;
+    
+    msg!("LP Growth quote: {} lamports + {} tokens → ~{} LP",
+         sol_to_add, tokens_to_mint, estimated_lp_out);
+    
+    Ok(LpGrowthQuote {
+        sol_to_add,
+        tokens_to_mint,
+        estimated_lp_out,
+    })
+}
+
+// =============================================================================
+// SWAP FEES TO SOL
+// =============================================================================
+//
+// Fees arrive as PDOX (Token-2022 withheld amounts), but LP growth adds SOL.
+// This step swaps the LP share of fees to wrapped SOL via the DEX, then
+// unwraps by closing the WSOL account into the manager PDA:
+//
+//   accumulated_token_fees --swap--> WSOL --close--> accumulated_sol
+//
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SwapFeesToSol<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LP_GROWTH_SEED, lp_growth_manager.mint.as_ref()],
+        bump = lp_growth_manager.bump,
+    )]
+    pub lp_growth_manager: Account<'info, LpGrowthManager>,
+    
+    /// LP share fee tokens (swap input)
+    #[account(
+        mut,
+        constraint = fee_token_account.key() == lp_growth_manager.fee_accumulation_account @ ParadoxError::InvalidVault,
+    )]
+    pub fee_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Wrapped SOL account owned by the manager PDA (swap output, closed to unwrap)
+    #[account(
+        mut,
+        constraint = wsol_account.mint == native_mint::ID @ ParadoxError::InvalidVault,
+        constraint = wsol_account.owner == lp_growth_manager.key() @ ParadoxError::InvalidVault,
+    )]
+    pub wsol_account: InterfaceAccount<'info, TokenAccount>,
+    
+    // =========================================================================
+    // DEV NOTE: Add your DEX swap accounts here (see ExecuteLpGrowth)
+    // =========================================================================
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn swap_fees_to_sol_handler(
+    ctx: Context<SwapFeesToSol>,
+    token_amount: u64,
+    min_sol_out: u64,
+) -> Result<()> {
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    require!(!ctx.accounts.lp_growth_manager.is_locked, ParadoxError::LpGrowthLocked);
+    require!(
+        token_amount > 0 && token_amount <= ctx.accounts.lp_growth_manager.accumulated_token_fees,
+        ParadoxError::InsufficientFees
+    );
+    
+    let wsol_before = ctx.accounts.wsol_account.amount;
+    
+    // =========================================================================
+    // DEV NOTE: Implement your DEX swap CPI here
+    // =========================================================================
+    //
+    //   swap(
+    //       &ctx.accounts.amm_pool,
+    //       ctx.accounts.fee_token_account,   // input: PDOX
+    //       ctx.accounts.wsol_account,        // output: WSOL
+    //       token_amount,
+    //       min_sol_out,
+    //       &[&[LP_GROWTH_SEED, manager.mint.as_ref(), &[manager.bump]]],
+    //   )?;
+    //
+    // =========================================================================
+    
+    ctx.accounts.wsol_account.reload()?;
+    let sol_received = ctx.accounts.wsol_account.amount.saturating_sub(wsol_before);
+    require!(sol_received >= min_sol_out, ParadoxError::InsufficientFees);
+    
+    // Unwrap: close WSOL account, lamports go to the manager PDA
+    let manager = &ctx.accounts.lp_growth_manager;
+    let seeds: &[&[u8]] = &[
+        LP_GROWTH_SEED,
+        manager.mint.as_ref(),
+        &[manager.bump],
+    ];
+    
+    close_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.wsol_account.to_account_info(),
+                destination: manager.to_account_info(),
+                authority: manager.to_account_info(),
+            },
+            &[seeds],
+        ),
+    )?;
+    
+    // Reconcile (checked arithmetic)
+    let manager = &mut ctx.accounts.lp_growth_manager;
+    manager.accumulated_token_fees = manager.accumulated_token_fees
+        .checked_sub(token_amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    manager.accumulated_sol = manager.accumulated_sol
+        .checked_add(sol_received)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    emit!(FeesSwappedToSol {
+        mint: manager.mint,
+        tokens_swapped: token_amount,
+        sol_received,
+        accumulated_sol: manager.accumulated_sol,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// SET LP POOLS (multi-venue split)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetLpGrowthPools<'info> {
+    #[account(
+        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, lp_growth_manager.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LP_GROWTH_SEED, lp_growth_manager.mint.as_ref()],
+        bump = lp_growth_manager.bump,
+    )]
+    pub lp_growth_manager: Account<'info, LpGrowthManager>,
+}
+
+/// Split growth SOL between lp_pool and a second pool
+/// Pubkey::default() with weights (10000, 0) goes back to a single pool
+pub fn set_pools_handler(
+    ctx: Context<SetLpGrowthPools>,
+    secondary_lp_pool: Pubkey,
+    primary_weight_bps: u16,
+    secondary_weight_bps: u16,
+) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    
+    let manager = &mut ctx.accounts.lp_growth_manager;
+    
+    LpGrowthManager::validate_pools(
+        &manager.lp_pool,
+        &secondary_lp_pool,
+        primary_weight_bps,
+        secondary_weight_bps,
+    )?;
+    
+    manager.secondary_lp_pool = secondary_lp_pool;
+    manager.primary_pool_weight_bps = primary_weight_bps;
+    manager.secondary_pool_weight_bps = secondary_weight_bps;
+    
+    msg!("LP growth pools: {} ({} bps) / {} ({} bps)",
+         manager.lp_pool, primary_weight_bps, secondary_lp_pool, secondary_weight_bps);
+    
+    emit!(LpGrowthPoolsUpdated {
+        mint: manager.mint,
+        lp_pool: manager.lp_pool,
+        primary_weight_bps,
+        secondary_lp_pool,
+        secondary_weight_bps,
+        updated_by: ctx.accounts.admin.key(),
     });
     
     Ok(())
@@ -226,6 +595,9 @@ pub struct LockLpGrowth<'info> {
 }
 
 pub fn lock_handler(ctx: Context<LockLpGrowth>) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    
     let manager = &mut ctx.accounts.lp_growth_manager;
     
     manager.is_locked = true;
@@ -265,9 +637,14 @@ pub struct UnlockLpGrowth<'info> {
 }
 
 pub fn unlock_handler(ctx: Context<UnlockLpGrowth>) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    
     let manager = &mut ctx.accounts.lp_growth_manager;
     
     manager.is_locked = false;
+    // Re-baseline the price circuit breaker after a manual review
+    manager.last_known_price = 0;
     
     emit!(LpGrowthUnlocked {
         mint: manager.mint,
@@ -0,0 +1,277 @@
+/**
+ * LP Growth - Meteora DLMM
+ * 
+ * Growth path for tokens launched on Meteora's DLMM. Liquidity goes into a
+ * DLMM position owned by the LP growth PDA, deposited into the active bin.
+ * The DLMM CPI is behind the `meteora` feature so other builds don't pull
+ * the dependency - without it the instruction returns MeteoraNotEnabled.
+ * 
+ * ACCOUNTS (in DLMM add_liquidity order where applicable):
+ * - lb_pair:                     DLMM pair, one of the manager's pools (lp_pool
+ *                                or secondary_lp_pool) - only its weighted share
+ *                                of accumulated_sol is deposited, the rest stays
+ *                                for the other venue
+ * - position:                    DLMM position, owner = lp_growth_manager PDA
+ * - bin_array_bitmap_extension:  only for pairs whose active bin is outside
+ *                                the default bitmap range
+ * - reserve_x / reserve_y:       pair reserves (one PDOX, one WSOL)
+ * - bin_array_lower / upper:     bin arrays covering the active bin
+ *                                (both the same array if it isn't on an edge)
+ * - event_authority:             DLMM event authority PDA
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use anchor_spl::token_interface::{TokenInterface, TokenAccount, Mint};
+
+use crate::{
+    state::{LpGrowthManager, TokenConfig, ProgramStats},
+    lp_value::lp_value_from_reserves,
+    ParadoxError,
+    LP_GROWTH_SEED,
+    TOKEN_CONFIG_SEED,
+    LpGrowthExecuted,
+};
+
+/// Meteora DLMM program
+pub const METEORA_DLMM_PROGRAM_ID: Pubkey = pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+// =============================================================================
+// EXECUTE LP GROWTH (METEORA DLMM)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ExecuteLpGrowthMeteora<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [LP_GROWTH_SEED, lp_growth_manager.mint.as_ref()],
+        bump = lp_growth_manager.bump,
+    )]
+    pub lp_growth_manager: Account<'info, LpGrowthManager>,
+    
+    /// Mint authority must be the lp_growth_manager PDA (see assign_mint_authority_to_growth)
+    #[account(
+        mut,
+        constraint = mint.key() == lp_growth_manager.mint @ ParadoxError::InvalidAccountType,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    /// Optional: deployer-wide analytics (see init_program_stats)
+    #[account(
+        mut,
+        constraint = program_stats.deployer == token_config.deployer @ ParadoxError::Unauthorized,
+    )]
+    pub program_stats: Option<Account<'info, ProgramStats>>,
+    
+    // -------------------------------------------------------------------------
+    // Growth PDA token accounts (DLMM "user" side)
+    // -------------------------------------------------------------------------
+    
+    /// Freshly minted PDOX for the deposit
+    #[account(
+        mut,
+        constraint = growth_token_account.mint == mint.key() @ ParadoxError::InvalidVault,
+        constraint = growth_token_account.owner == lp_growth_manager.key() @ ParadoxError::InvalidVault,
+    )]
+    pub growth_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// accumulated_sol wrapped for the deposit
+    #[account(
+        mut,
+        constraint = growth_wsol_account.mint == native_mint::ID @ ParadoxError::InvalidVault,
+        constraint = growth_wsol_account.owner == lp_growth_manager.key() @ ParadoxError::InvalidVault,
+    )]
+    pub growth_wsol_account: InterfaceAccount<'info, TokenAccount>,
+    
+    // -------------------------------------------------------------------------
+    // Meteora DLMM accounts
+    // -------------------------------------------------------------------------
+    
+    /// CHECK: DLMM pair - must be one of the pools this manager grows
+    #[account(
+        mut,
+        constraint = lb_pair.key() == lp_growth_manager.lp_pool
+            || lb_pair.key() == lp_growth_manager.secondary_lp_pool @ ParadoxError::PoolNotInitialized,
+    )]
+    pub lb_pair: UncheckedAccount<'info>,
+    
+    /// CHECK: DLMM position owned by the growth PDA (validated by the DLMM program)
+    #[account(mut)]
+    pub position: UncheckedAccount<'info>,
+    
+    /// CHECK: Only needed when the active bin is outside the default bitmap
+    #[account(mut)]
+    pub bin_array_bitmap_extension: Option<UncheckedAccount<'info>>,
+    
+    /// Pair reserve X (PDOX or WSOL, validated by the DLMM program)
+    #[account(mut)]
+    pub reserve_x: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Pair reserve Y (the other side)
+    #[account(mut)]
+    pub reserve_y: InterfaceAccount<'info, TokenAccount>,
+    
+    /// CHECK: Bin array at or below the active bin
+    #[account(mut)]
+    pub bin_array_lower: UncheckedAccount<'info>,
+    
+    /// CHECK: Bin array at or above the active bin
+    #[account(mut)]
+    pub bin_array_upper: UncheckedAccount<'info>,
+    
+    /// CHECK: DLMM event authority PDA
+    pub event_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: Meteora DLMM program
+    #[account(
+        constraint = dlmm_program.key() == METEORA_DLMM_PROGRAM_ID @ ParadoxError::InvalidAccountType,
+    )]
+    pub dlmm_program: UncheckedAccount<'info>,
+    
+    /// Token program for PDOX (Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Token program for WSOL (SPL Token)
+    pub wsol_token_program: Interface<'info, TokenInterface>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposit into the active bin via the DLMM program
+/// Returns the number of tokens minted into the position
+#[cfg(feature = "meteora")]
+fn deposit_to_active_bin(
+    _accounts: &ExecuteLpGrowthMeteora,
+    sol_to_add: u64,
+    tokens_to_mint: u64,
+) -> Result<u64> {
+    // =========================================================================
+    // DEV NOTE: DLMM deposit (wire the DLMM CPI crate in here)
+    // =========================================================================
+    //
+    // Signed by the growth PDA: [LP_GROWTH_SEED, manager.mint, manager.bump]
+    //
+    // 1. Wrap SOL: system transfer sol_to_add lamports from the PDA to
+    //    growth_wsol_account, then sync_native
+    // 2. mint_to(growth_token_account, tokens_to_mint)
+    // 3. add_liquidity_by_strategy on dlmm_program with
+    //      amount_x / amount_y = tokens_to_mint / sol_to_add (per reserve order)
+    //      active_id = lb_pair.active_id, max_active_bin_slippage = 0
+    //      strategy = SpotBalanced { min_bin_id: active_id, max_bin_id: active_id }
+    //    accounts: position, lb_pair, bin_array_bitmap_extension,
+    //      growth_token_account / growth_wsol_account (user_token_x/y),
+    //      reserve_x, reserve_y, token_x/y mints, bin_array_lower/upper,
+    //      sender = lp_growth_manager, token_x/y programs, event_authority,
+    //      program = dlmm_program
+    //
+    // =========================================================================
+    
+    // Placeholder: Just log that LP growth would happen
+    msg!("LP Growth (DLMM): Would add {} lamports + {} tokens to active bin",
+         sol_to_add, tokens_to_mint);
+    
+    Ok(0) // Replace with tokens_to_mint once the CPI is wired
+}
+
+#[cfg(not(feature = "meteora"))]
+fn deposit_to_active_bin(
+    _accounts: &ExecuteLpGrowthMeteora,
+    _sol_to_add: u64,
+    _tokens_to_mint: u64,
+) -> Result<u64> {
+    err!(ParadoxError::MeteoraNotEnabled)
+}
+
+pub fn execute_meteora_handler(ctx: Context<ExecuteLpGrowthMeteora>) -> Result<()> {
+    log_compute!("execute_lp_growth_meteora: entry");
+    
+    require!(ctx.accounts.lp_growth_manager.is_initialized(), ParadoxError::NotInitialized);
+    
+    // Growth mints new tokens - fail clearly here instead of inside the mint CPI
+    require!(
+        Option::<Pubkey>::from(ctx.accounts.mint.mint_authority)
+            == Some(ctx.accounts.lp_growth_manager.key()),
+        ParadoxError::MintAuthorityMismatch
+    );
+    
+    // Reserves must be this pair's PDOX / WSOL sides (either order)
+    let mint_key = ctx.accounts.mint.key();
+    let (token_reserve, sol_reserve) = match (ctx.accounts.reserve_x.mint, ctx.accounts.reserve_y.mint) {
+        (x, y) if x == mint_key && y == native_mint::ID => {
+            (ctx.accounts.reserve_x.amount, ctx.accounts.reserve_y.amount)
+        }
+        (x, y) if x == native_mint::ID && y == mint_key => {
+            (ctx.accounts.reserve_y.amount, ctx.accounts.reserve_x.amount)
+        }
+        _ => return err!(ParadoxError::InvalidVault),
+    };
+    
+    let manager = &ctx.accounts.lp_growth_manager;
+    let clock = Clock::get()?;
+    
+    // Validate
+    require!(!manager.is_locked, ParadoxError::LpGrowthLocked);
+    require!(manager.can_execute_growth(clock.unix_timestamp), ParadoxError::CooldownNotPassed);
+    require!(manager.has_enough_fees(), ParadoxError::InsufficientFees);
+    
+    // This pair's weighted share (everything for single-pool managers)
+    let sol_to_add = manager
+        .allocation_for(&ctx.accounts.lb_pair.key(), manager.accumulated_sol)
+        .ok_or(ParadoxError::PoolNotInitialized)?;
+    
+    // DEV: Read the active bin price from lb_pair (active_id + bin_step):
+    //   price = (1 + bin_step / 10_000) ^ active_id, converted to PRICE_SCALE
+    // Aggregate reserves span every bin, so they are NOT the spot price here.
+    // 0 = unknown price, circuit breaker is skipped.
+    let current_price: u64 = 0; // Replace with active bin price
+    
+    // Circuit breaker: don't mint into a crash
+    require!(
+        !manager.price_dropped_too_far(current_price),
+        ParadoxError::GrowthPausedByPriceDrop
+    );
+    
+    let tokens_to_mint = manager.calculate_tokens_to_mint(sol_to_add, current_price)?;
+    
+    // Stamp the cooldown before any CPI so nothing re-entering this block can grow again
+    ctx.accounts.lp_growth_manager.last_growth_time = clock.unix_timestamp;
+    
+    log_compute!("execute_lp_growth_meteora: before mint/add-liquidity CPI");
+    let tokens_minted = deposit_to_active_bin(&ctx.accounts, sol_to_add, tokens_to_mint)?;
+    
+    // Post-growth value, priced at the pre-growth active bin price
+    let new_lp_value = lp_value_from_reserves(
+        sol_reserve.checked_add(sol_to_add).ok_or(ParadoxError::MathOverflow)?,
+        token_reserve.checked_add(tokens_minted).ok_or(ParadoxError::MathOverflow)?,
+        current_price,
+    )?;
+    
+    let manager = &mut ctx.accounts.lp_growth_manager;
+    manager.record_growth(sol_to_add, tokens_minted, current_price, clock.unix_timestamp)?;
+    
+    if let Some(stats) = &mut ctx.accounts.program_stats {
+        stats.record_lp_growth(sol_to_add);
+    }
+    
+    emit!(LpGrowthExecuted {
+        mint: manager.mint,
+        sol_added: sol_to_add,
+        tokens_minted,
+        new_lp_value,
+        pending_token_fees: manager.accumulated_token_fees,
+    });
+    
+    Ok(())
+}
@@ -13,18 +13,30 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
     TokenInterface, TokenAccount, Mint, 
-    TransferChecked, transfer_checked,
     InterfaceAccount, Interface,
 };
 
 use crate::{
-    state::{LpLock, LpLockStatus, HolderBalancesSnapshot, HolderSnapshot},
+    state::{
+        LpLock, LpLockStatus, PhaseInfo, PendingWithdrawalView, SnapshotReason, HolderBalancesSnapshot, HolderSnapshot,
+        LpHolderGuard, MAX_PENDING_WITHDRAWALS, MAX_WITHDRAWAL_BPS, MAX_HOLDERS_PER_PAGE,
+    },
+    transfer_hook::transfer_checked_with_hook,
     ParadoxError,
     LP_LOCK_SEED,
+    LP_HOLDER_GUARD_SEED,
+    CURRENT_ACCOUNT_VERSION,
     LpLockCreated,
     LpWithdrawalAnnounced,
     LpWithdrawalExecuted,
     LpWithdrawalCancelled,
+    LpWithdrawalExpired,
+    LpEmergencyWithdrawal,
+    LpLockAdminTransferred,
+    LpLockEmergencyMultisigUpdated,
+    LpLockPhaseAdvanced,
+    LpLockClosed,
+    SnapshotInvalidated,
 };
 
 /// Seed for holder snapshot
@@ -72,7 +84,22 @@ pub fn create_pool_and_lock_handler(
     token_amount: u64,
     _timelock_seconds: Option<i64>, // Ignored - uses progressive system
     _max_withdrawal_bps: Option<u16>, // Ignored - 100% allowed with proper notice
+    allowed_recipient: Option<Pubkey>, // None = any recipient (current behavior)
+    permanent_floor_bps: Option<u16>, // None = 0 (full pull allowed with notice)
+    max_pending_allowed: Option<u8>, // None = 3 (array size)
 ) -> Result<()> {
+    let permanent_floor_bps = permanent_floor_bps.unwrap_or(0);
+    require!(
+        permanent_floor_bps <= MAX_WITHDRAWAL_BPS,
+        ParadoxError::InvalidLiquidityFloor
+    );
+    
+    let max_pending_allowed = max_pending_allowed.unwrap_or(MAX_PENDING_WITHDRAWALS as u8);
+    require!(
+        max_pending_allowed >= 1 && max_pending_allowed as usize <= MAX_PENDING_WITHDRAWALS,
+        ParadoxError::InvalidMaxPendingWithdrawals
+    );
+    
     let lp_lock = &mut ctx.accounts.lp_lock;
     
     // =========================================================================
@@ -80,6 +107,7 @@ pub fn create_pool_and_lock_handler(
     // =========================================================================
     
     let lp_tokens_received: u64 = 0; // Replace with actual LP tokens
+    let allowed_recipient = allowed_recipient.unwrap_or_default();
     
     lp_lock.initialize(
         ctx.accounts.mint.key(),
@@ -89,8 +117,12 @@ pub fn create_pool_and_lock_handler(
         ctx.accounts.creator.key(),
         ctx.accounts.emergency_multisig.key(),
         lp_tokens_received,
+        allowed_recipient,
+        permanent_floor_bps,
+        max_pending_allowed,
         ctx.bumps.lp_lock,
-    );
+        Clock::get()?.unix_timestamp,
+    )?;
     
     let phase_name = lp_lock.get_phase_name();
     let timelock_hours = lp_lock.get_required_timelock() / 3600;
@@ -108,6 +140,13 @@ pub fn create_pool_and_lock_handler(
     msg!("║");
     msg!("║ SAFETY: Snapshot taken before any withdrawal");
     msg!("║         Restore capability for relaunch");
+    if allowed_recipient != Pubkey::default() {
+        msg!("║         Withdrawals pinned to: {}", allowed_recipient);
+    }
+    if permanent_floor_bps > 0 {
+        msg!("║         Permanent floor: {} bps of initial LP", permanent_floor_bps);
+    }
+    msg!("║         Max pending withdrawals: {}", max_pending_allowed);
     msg!("╚══════════════════════════════════════════════════════════════╝");
     
     emit!(LpLockCreated {
@@ -117,6 +156,9 @@ pub fn create_pool_and_lock_handler(
         timelock_seconds: lp_lock.get_required_timelock(),
         max_withdrawal_bps: 10000, // 100%
         admin: ctx.accounts.creator.key(),
+        allowed_recipient,
+        permanent_floor_bps,
+        max_pending_allowed,
     });
     
     Ok(())
@@ -141,16 +183,76 @@ pub struct TakeSnapshot<'info> {
         bump = lp_lock.bump,
     )]
     pub lp_lock: Account<'info, LpLock>,
+    
+    /// Optional: first holder page for the snapshot being taken
+    /// When passed, holder_count must equal the entries across the page chain
+    /// (later pages in remaining_accounts, in next_account order)
+    #[account(
+        constraint = holder_pages.lp_lock == lp_lock.key() @ ParadoxError::InvalidAccountType,
+    )]
+    pub holder_pages: Option<Account<'info, HolderBalancesSnapshot>>,
 }
 
-pub fn take_snapshot_handler(
-    ctx: Context<TakeSnapshot>,
-    reason: [u8; 32],
+/// Count holder entries across a page chain for `snapshot_id`
+/// Pages must belong to this lock and link exactly via next_account
+fn count_holder_pages<'info>(
+    lp_lock_key: Pubkey,
+    snapshot_id: u64,
+    first: &Account<'info, HolderBalancesSnapshot>,
+    rest: &'info [AccountInfo<'info>],
+) -> Result<u32> {
+    require!(first.snapshot_id == snapshot_id, ParadoxError::SnapshotNotFound);
+    
+    let mut count = first.holders.len() as u32;
+    let mut next = first.next_account;
+    
+    for info in rest {
+        require!(next == Some(info.key()), ParadoxError::InvalidAccountType);
+        
+        let page = Account::<HolderBalancesSnapshot>::try_from(info)?;
+        require!(
+            page.lp_lock == lp_lock_key && page.snapshot_id == snapshot_id,
+            ParadoxError::InvalidAccountType
+        );
+        
+        count = count
+            .checked_add(page.holders.len() as u32)
+            .ok_or(ParadoxError::MathOverflow)?;
+        next = page.next_account;
+    }
+    
+    // Chain must be complete
+    require!(next.is_none(), ParadoxError::InvalidAccountType);
+    
+    Ok(count)
+}
+
+pub fn take_snapshot_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, TakeSnapshot<'info>>,
+    reason: SnapshotReason,
+    note: [u8; 31],
     sol_reserve: u64,
     token_reserve: u64,
     total_supply: u64,
     holder_count: u32,
 ) -> Result<u64> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    // Attested mode: holder_count must match the written holder pages
+    let attested = match &ctx.accounts.holder_pages {
+        Some(first) => {
+            let counted = count_holder_pages(
+                ctx.accounts.lp_lock.key(),
+                ctx.accounts.lp_lock.snapshot_counter + 1, // id this snapshot will get
+                first,
+                ctx.remaining_accounts,
+            )?;
+            require!(counted == holder_count, ParadoxError::HolderCountMismatch);
+            true
+        }
+        None => false,
+    };
+    
     let lp_lock = &mut ctx.accounts.lp_lock;
     
     // SECURITY: Require actual data - snapshots with all zeros are useless for restore
@@ -161,21 +263,134 @@ pub fn take_snapshot_handler(
     
     let snapshot_id = lp_lock.take_snapshot(
         reason,
+        &note,
         sol_reserve,
         token_reserve,
         total_supply,
         holder_count,
+        Clock::get()?.unix_timestamp,
     );
     
-    msg!("📸 Snapshot #{} taken", snapshot_id);
+    msg!("📸 Snapshot #{} taken ({})", snapshot_id, reason.name());
     msg!("   LP Tokens: {}", lp_lock.lp_tokens_locked);
     msg!("   SOL Reserve: {}", sol_reserve);
     msg!("   Token Reserve: {}", token_reserve);
-    msg!("   Holders: {}", holder_count);
+    msg!("   Holders: {} ({})", holder_count, if attested { "attested by pages" } else { "asserted" });
     
     Ok(snapshot_id)
 }
 
+// =============================================================================
+// WRITE HOLDER SNAPSHOT PAGE
+// =============================================================================
+
+#[derive(Accounts)]
+#[instruction(snapshot_id: u64, page_index: u16, holders: Vec<HolderSnapshot>)]
+pub struct WriteHolderSnapshotPage<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == lp_lock.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = HolderBalancesSnapshot::size_for_holders(holders.len()),
+        seeds = [
+            HOLDER_SNAPSHOT_SEED,
+            lp_lock.key().as_ref(),
+            &snapshot_id.to_le_bytes(),
+            &page_index.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub holder_page: Account<'info, HolderBalancesSnapshot>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Write one page of holder balances ahead of an attested take_lp_snapshot
+/// `snapshot_id` is the id the snapshot will get (snapshot_counter + 1)
+pub fn write_holder_snapshot_page_handler(
+    ctx: Context<WriteHolderSnapshotPage>,
+    snapshot_id: u64,
+    _page_index: u16,
+    holders: Vec<HolderSnapshot>,
+    next_account: Option<Pubkey>,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    require!(
+        !holders.is_empty() && holders.len() <= MAX_HOLDERS_PER_PAGE,
+        ParadoxError::SnapshotDataRequired
+    );
+    
+    let page = &mut ctx.accounts.holder_page;
+    page.snapshot_id = snapshot_id;
+    page.lp_lock = ctx.accounts.lp_lock.key();
+    page.timestamp = Clock::get()?.unix_timestamp;
+    page.holder_count = holders.len() as u32;
+    page.holders = holders;
+    page.next_account = next_account;
+    page.bump = ctx.bumps.holder_page;
+    
+    msg!("Holder page written for snapshot #{}: {} holders", snapshot_id, page.holder_count);
+    
+    Ok(())
+}
+
+// =============================================================================
+// INVALIDATE SNAPSHOT
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InvalidateSnapshot<'info> {
+    #[account(
+        constraint = admin.key() == lp_lock.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+}
+
+pub fn invalidate_snapshot_handler(
+    ctx: Context<InvalidateSnapshot>,
+    snapshot_id: u64,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    
+    lp_lock.invalidate_snapshot(snapshot_id)?;
+    
+    msg!("🚫 Snapshot #{} invalidated", snapshot_id);
+    msg!("   Latest restorable: #{}", lp_lock.latest_restorable_snapshot);
+    
+    emit!(SnapshotInvalidated {
+        mint: ctx.accounts.mint.key(),
+        snapshot_id,
+        invalidated_by: ctx.accounts.admin.key(),
+        latest_restorable_snapshot: lp_lock.latest_restorable_snapshot,
+    });
+    
+    Ok(())
+}
+
 // =============================================================================
 // ANNOUNCE WITHDRAWAL (with automatic snapshot)
 // =============================================================================
@@ -195,6 +410,13 @@ pub struct AnnounceWithdrawal<'info> {
         bump = lp_lock.bump,
     )]
     pub lp_lock: Account<'info, LpLock>,
+    
+    /// Required once the holder guard is enabled
+    #[account(
+        seeds = [LP_HOLDER_GUARD_SEED, lp_lock.key().as_ref()],
+        bump = holder_guard.bump,
+    )]
+    pub holder_guard: Option<Account<'info, LpHolderGuard>>,
 }
 
 pub fn announce_withdrawal_handler(
@@ -207,10 +429,19 @@ pub fn announce_withdrawal_handler(
     total_supply: u64,     // REQUIRED: Total token supply
     holder_count: u32,     // REQUIRED: Number of holders
 ) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    // Reason must round-trip exactly (trailing zero padding allowed)
+    let reason_len = reason.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    let reason_str = core::str::from_utf8(&reason[..reason_len])
+        .map_err(|_| ParadoxError::InvalidReason)?
+        .to_string();
+    
     let lp_lock = &mut ctx.accounts.lp_lock;
+    let clock = Clock::get()?;
     
-    // Validate amount
-    require!(amount <= lp_lock.lp_tokens_locked, ParadoxError::InsufficientLpTokens);
+    // Validate amount (net of other pending withdrawals)
+    require!(amount <= lp_lock.uncommitted_lp_tokens(), ParadoxError::InsufficientLpTokens);
     
     // SECURITY: Require actual reserve data for the snapshot
     require!(
@@ -218,23 +449,31 @@ pub fn announce_withdrawal_handler(
         ParadoxError::SnapshotDataRequired
     );
     
-    // Take automatic snapshot before withdrawal with actual data
-    let mut snapshot_reason = [0u8; 32];
-    snapshot_reason[..16].copy_from_slice(b"PRE_WITHDRAWAL__");
+    // Holder guard: the last recorded count can't be under-reported away
+    if lp_lock.holder_guard_enabled {
+        let guard = ctx.accounts.holder_guard.as_ref().ok_or(ParadoxError::InvalidHolderGuard)?;
+        let holders = holder_count.max(lp_lock.latest_valid_holder_count());
+        if let Some(max_amount) = guard.max_withdrawal(lp_lock.lp_tokens_locked, holders) {
+            require!(amount <= max_amount, ParadoxError::HolderGuardLimitExceeded);
+        }
+    }
     
+    // Take automatic snapshot before withdrawal with actual data
     let snapshot_id = lp_lock.take_snapshot(
-        snapshot_reason,
+        SnapshotReason::PreWithdrawal,
+        &[],
         sol_reserve,
         token_reserve,
         total_supply,
         holder_count,
+        clock.unix_timestamp,
     );
     
     // Announce withdrawal
-    let slot = lp_lock.announce_withdrawal(amount, recipient, reason, snapshot_id)?;
+    let slot = lp_lock.announce_withdrawal(amount, recipient, reason, snapshot_id, clock.unix_timestamp)?;
     
     let phase_name = lp_lock.get_phase_name();
-    let timelock = lp_lock.get_required_timelock();
+    let timelock = lp_lock.get_required_timelock_at(clock.unix_timestamp);
     let execute_after = lp_lock.pending_withdrawals[slot].execute_after;
     
     msg!("╔══════════════════════════════════════════════════════════════╗");
@@ -246,6 +485,7 @@ pub fn announce_withdrawal_handler(
     msg!("║ Timelock: {} hours", timelock / 3600);
     msg!("║ Executable after: {}", execute_after);
     msg!("║ Snapshot ID: #{} (for restore)", snapshot_id);
+    msg!("║ Execution nonce: {}", lp_lock.pending_withdrawals[slot].execution_nonce());
     msg!("║");
     msg!("║ ⚠️  VISIBLE ON-CHAIN - Everyone can see this!");
     msg!("╚══════════════════════════════════════════════════════════════╝");
@@ -254,10 +494,11 @@ pub fn announce_withdrawal_handler(
         mint: ctx.accounts.mint.key(),
         amount,
         recipient,
-        reason: String::from_utf8_lossy(&reason).to_string(),
-        announced_at: Clock::get()?.unix_timestamp,
+        reason: reason_str,
+        announced_at: clock.unix_timestamp,
         execute_after,
         slot: slot as u8,
+        execution_nonce: lp_lock.pending_withdrawals[slot].execution_nonce(),
     });
     
     Ok(())
@@ -268,6 +509,7 @@ pub fn announce_withdrawal_handler(
 // =============================================================================
 
 #[derive(Accounts)]
+#[instruction(slot: u8)]
 pub struct ExecuteWithdrawal<'info> {
     pub executor: Signer<'info>,
     
@@ -286,48 +528,67 @@ pub struct ExecuteWithdrawal<'info> {
     )]
     pub lp_vault: InterfaceAccount<'info, TokenAccount>,
     
-    /// CHECK: Must match pending withdrawal recipient
-    #[account(mut)]
-    pub recipient_lp_account: UncheckedAccount<'info>,
+    /// LP token mint (transfer_checked needs it, and its hook if any)
+    #[account(
+        constraint = lp_token_mint.key() == lp_lock.lp_token_mint @ ParadoxError::InvalidVault,
+    )]
+    pub lp_token_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Recipient's LP token account - owner must match pending withdrawal recipient
+    #[account(
+        mut,
+        constraint = lp_lock.pending_withdrawals
+            .get(slot as usize)
+            .map(|pw| pw.recipient) == Some(recipient_lp_account.owner) @ ParadoxError::Unauthorized,
+    )]
+    pub recipient_lp_account: InterfaceAccount<'info, TokenAccount>,
     
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn execute_withdrawal_handler(
-    ctx: Context<ExecuteWithdrawal>,
+pub fn execute_withdrawal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteWithdrawal<'info>>,
     slot: u8,
+    nonce: u64,
 ) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
     let lp_lock = &mut ctx.accounts.lp_lock;
     let slot_usize = slot as usize;
+    let clock = Clock::get()?;
     
     // Validate
-    require!(lp_lock.can_execute_withdrawal(slot_usize), ParadoxError::TimelockNotExpired);
+    require!(
+        lp_lock.can_execute_withdrawal_at(slot_usize, clock.unix_timestamp),
+        ParadoxError::TimelockNotExpired
+    );
     
     let pending = &lp_lock.pending_withdrawals[slot_usize];
-    let time_waited = Clock::get()?.unix_timestamp - pending.announced_at;
+    let time_waited = clock.unix_timestamp - pending.announced_at;
     
-    // Execute withdrawal
-    let (amount, recipient) = lp_lock.execute_withdrawal(slot_usize)?;
+    // Execute withdrawal - state is settled and persisted before the CPI
+    // (a failed transfer reverts the whole transaction)
+    let (amount, recipient) = lp_lock.execute_withdrawal(slot_usize, nonce, clock.unix_timestamp)?;
+    lp_lock.exit(ctx.program_id)?;
     
     // Transfer LP tokens
     let mint_key = ctx.accounts.mint.key();
-    let seeds = &[
+    let seeds: &[&[u8]] = &[
         LP_LOCK_SEED,
         mint_key.as_ref(),
         &[lp_lock.bump],
     ];
     
-    transfer(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.lp_vault.to_account_info(),
-                to: ctx.accounts.recipient_lp_account.to_account_info(),
-                authority: lp_lock.to_account_info(),
-            },
-            &[seeds],
-        ),
+    transfer_checked_with_hook(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.lp_vault.to_account_info(),
+        &ctx.accounts.lp_token_mint.to_account_info(),
+        &ctx.accounts.recipient_lp_account.to_account_info(),
+        &lp_lock.to_account_info(),
+        ctx.remaining_accounts,
         amount,
+        ctx.accounts.lp_token_mint.decimals,
+        &[seeds],
     )?;
     
     msg!("✅ LP Withdrawal executed after {}h timelock", time_waited / 3600);
@@ -372,16 +633,22 @@ pub fn cancel_withdrawal_handler(
     ctx: Context<CancelWithdrawal>,
     slot: u8,
 ) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    // Bounds check before touching pending_withdrawals (mirrors LpLock::cancel_withdrawal)
+    require!((slot as usize) < MAX_PENDING_WITHDRAWALS, ParadoxError::InvalidWithdrawalSlot);
+    
     let lp_lock = &mut ctx.accounts.lp_lock;
     
     let pending = &lp_lock.pending_withdrawals[slot as usize];
     let amount = pending.amount;
     let recipient = pending.recipient;
     
-    lp_lock.cancel_withdrawal(slot as usize)?;
+    lp_lock.cancel_withdrawal(slot as usize, Clock::get()?.unix_timestamp)?;
     
     msg!("❌ LP Withdrawal cancelled");
     msg!("   Amount: {} LP tokens", amount);
+    msg!("   Announced: {} | Cancelled: {}", lp_lock.announcements_count, lp_lock.cancellations_count);
     
     emit!(LpWithdrawalCancelled {
         mint: ctx.accounts.mint.key(),
@@ -389,6 +656,107 @@ pub fn cancel_withdrawal_handler(
         recipient,
         cancelled_by: ctx.accounts.admin.key(),
         slot,
+        announcements_count: lp_lock.announcements_count,
+        cancellations_count: lp_lock.cancellations_count,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// EMERGENCY LP WITHDRAWAL (15 min window)
+// =============================================================================
+//
+// One-time escape hatch for a botched launch (wrong pool, wrong ratio).
+// Only usable by the emergency multisig within LP_EMERGENCY_WINDOW_SECONDS
+// of lock creation - after that, all withdrawals go through the timelock.
+//
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct EmergencyLpWithdrawal<'info> {
+    #[account(
+        constraint = emergency_multisig.key() == lp_lock.emergency_multisig @ ParadoxError::Unauthorized
+    )]
+    pub emergency_multisig: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    
+    #[account(
+        constraint = lp_token_mint.key() == lp_lock.lp_token_mint @ ParadoxError::InvalidVault,
+    )]
+    pub lp_token_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = lp_vault.key() == lp_lock.lp_vault @ ParadoxError::InvalidVault,
+    )]
+    pub lp_vault: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Recipient's LP token account - must respect the recipient allowlist
+    #[account(
+        mut,
+        constraint = lp_lock.is_recipient_allowed(&recipient_lp_account.owner) @ ParadoxError::RecipientNotAllowed,
+    )]
+    pub recipient_lp_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn emergency_withdrawal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, EmergencyLpWithdrawal<'info>>,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    require!(reason.len() <= 64, ParadoxError::InvalidReason);
+    
+    let clock = Clock::get()?;
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    
+    // State is settled and persisted before the CPI (a failed transfer reverts the whole transaction)
+    lp_lock.emergency_withdraw(amount, clock.unix_timestamp)?;
+    lp_lock.exit(ctx.program_id)?;
+    
+    // Transfer LP tokens
+    let mint_key = ctx.accounts.mint.key();
+    let seeds: &[&[u8]] = &[
+        LP_LOCK_SEED,
+        mint_key.as_ref(),
+        &[lp_lock.bump],
+    ];
+    
+    transfer_checked_with_hook(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.lp_vault.to_account_info(),
+        &ctx.accounts.lp_token_mint.to_account_info(),
+        &ctx.accounts.recipient_lp_account.to_account_info(),
+        &lp_lock.to_account_info(),
+        ctx.remaining_accounts,
+        amount,
+        ctx.accounts.lp_token_mint.decimals,
+        &[seeds],
+    )?;
+    
+    msg!("🚨 EMERGENCY LP WITHDRAWAL");
+    msg!("   Amount: {} LP tokens", amount);
+    msg!("   Seconds since lock: {}", clock.unix_timestamp - lp_lock.created_at);
+    msg!("   Remaining locked: {}", lp_lock.lp_tokens_locked);
+    msg!("   Emergency path is now permanently disabled");
+    
+    emit!(LpEmergencyWithdrawal {
+        mint: ctx.accounts.mint.key(),
+        creator: ctx.accounts.emergency_multisig.key(),
+        lp_amount: amount,
+        reason,
+        timestamp: clock.unix_timestamp,
     });
     
     Ok(())
@@ -420,6 +788,12 @@ pub struct RestoreFromSnapshot<'info> {
     #[account(mut)]
     pub source_lp_account: InterfaceAccount<'info, TokenAccount>,
     
+    /// Optional: governance co-signature to restore from a snapshot past max age
+    #[account(
+        constraint = governance.key() == lp_lock.governance @ ParadoxError::GovernanceRequired
+    )]
+    pub governance: Option<Signer<'info>>,
+    
     pub token_program: Interface<'info, TokenInterface>,
 }
 
@@ -428,6 +802,8 @@ pub fn restore_from_snapshot_handler(
     snapshot_id: u64,
     lp_amount: u64,
 ) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
     let lp_lock = &mut ctx.accounts.lp_lock;
     
     // Validate snapshot exists
@@ -436,6 +812,13 @@ pub fn restore_from_snapshot_handler(
     
     require!(!snapshot.was_restored, ParadoxError::AlreadyFinalized);
     
+    // Stale reserves shouldn't anchor a relaunch unless governance signs off
+    let clock = Clock::get()?;
+    if lp_lock.is_snapshot_stale(snapshot, clock.unix_timestamp) {
+        require!(ctx.accounts.governance.is_some(), ParadoxError::SnapshotTooOld);
+        msg!("⚠️ Snapshot #{} is past max age - governance override", snapshot_id);
+    }
+    
     msg!("╔══════════════════════════════════════════════════════════════╗");
     msg!("║           RESTORING FROM SNAPSHOT #{}                        ║", snapshot_id);
     msg!("╠══════════════════════════════════════════════════════════════╣");
@@ -460,7 +843,7 @@ pub fn restore_from_snapshot_handler(
     )?;
     
     // Update state
-    lp_lock.restore_from_snapshot(lp_amount);
+    lp_lock.restore_from_snapshot(lp_amount, clock.unix_timestamp)?;
     lp_lock.mark_snapshot_restored(snapshot_id);
     
     msg!("✅ LP Lock restored successfully");
@@ -470,6 +853,234 @@ pub fn restore_from_snapshot_handler(
     Ok(())
 }
 
+// =============================================================================
+// SET MAX SNAPSHOT AGE
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetMaxSnapshotAge<'info> {
+    #[account(
+        constraint = governance.key() == lp_lock.governance @ ParadoxError::GovernanceRequired
+    )]
+    pub governance: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+}
+
+pub fn set_max_snapshot_age_handler(
+    ctx: Context<SetMaxSnapshotAge>,
+    max_snapshot_age_seconds: u32,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    require!(max_snapshot_age_seconds > 0, ParadoxError::InvalidThresholds);
+    
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    let old_age = lp_lock.effective_max_snapshot_age();
+    lp_lock.max_snapshot_age_seconds = max_snapshot_age_seconds;
+    
+    msg!("Max snapshot age: {}s → {}s", old_age, max_snapshot_age_seconds);
+    
+    Ok(())
+}
+
+// =============================================================================
+// SET EXECUTION GRACE
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetExecutionGrace<'info> {
+    #[account(
+        constraint = governance.key() == lp_lock.governance @ ParadoxError::GovernanceRequired
+    )]
+    pub governance: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+}
+
+/// 0 disables expiry (withdrawals stay executable indefinitely)
+pub fn set_execution_grace_handler(
+    ctx: Context<SetExecutionGrace>,
+    execution_grace_hours: u16,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    let old_hours = lp_lock.execution_grace_hours;
+    lp_lock.execution_grace_hours = execution_grace_hours;
+    
+    msg!("Execution grace: {}h → {}h (0 = never expires)", old_hours, execution_grace_hours);
+    
+    Ok(())
+}
+
+// =============================================================================
+// HOLDER GUARD
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitHolderGuard<'info> {
+    #[account(
+        mut,
+        constraint = governance.key() == lp_lock.governance @ ParadoxError::GovernanceRequired
+    )]
+    pub governance: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    
+    #[account(
+        init,
+        payer = governance,
+        space = LpHolderGuard::LEN,
+        seeds = [LP_HOLDER_GUARD_SEED, lp_lock.key().as_ref()],
+        bump,
+    )]
+    pub holder_guard: Account<'info, LpHolderGuard>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_holder_guard_handler(
+    ctx: Context<InitHolderGuard>,
+    holder_guard_threshold: u32,
+    holder_guard_max_bps: u16,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    require!(
+        holder_guard_max_bps > 0 && holder_guard_max_bps <= MAX_WITHDRAWAL_BPS,
+        ParadoxError::InvalidHolderGuard
+    );
+    
+    let guard = &mut ctx.accounts.holder_guard;
+    guard.lp_lock = ctx.accounts.lp_lock.key();
+    guard.holder_guard_threshold = holder_guard_threshold;
+    guard.holder_guard_max_bps = holder_guard_max_bps;
+    guard.bump = ctx.bumps.holder_guard;
+    guard.version = CURRENT_ACCOUNT_VERSION;
+    
+    ctx.accounts.lp_lock.holder_guard_enabled = true;
+    
+    msg!("Holder guard enabled: >{} holders → max {} bps per withdrawal",
+         holder_guard_threshold, holder_guard_max_bps);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetHolderGuard<'info> {
+    #[account(
+        constraint = governance.key() == lp_lock.governance @ ParadoxError::GovernanceRequired
+    )]
+    pub governance: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    
+    #[account(
+        mut,
+        seeds = [LP_HOLDER_GUARD_SEED, lp_lock.key().as_ref()],
+        bump = holder_guard.bump,
+    )]
+    pub holder_guard: Account<'info, LpHolderGuard>,
+}
+
+/// Threshold 0 stops the guard from applying (the account stays required)
+pub fn set_holder_guard_handler(
+    ctx: Context<SetHolderGuard>,
+    holder_guard_threshold: u32,
+    holder_guard_max_bps: u16,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    require!(
+        holder_guard_max_bps > 0 && holder_guard_max_bps <= MAX_WITHDRAWAL_BPS,
+        ParadoxError::InvalidHolderGuard
+    );
+    
+    let guard = &mut ctx.accounts.holder_guard;
+    let (old_threshold, old_bps) = (guard.holder_guard_threshold, guard.holder_guard_max_bps);
+    guard.holder_guard_threshold = holder_guard_threshold;
+    guard.holder_guard_max_bps = holder_guard_max_bps;
+    
+    msg!("Holder guard: >{} holders / {} bps → >{} holders / {} bps",
+         old_threshold, old_bps, holder_guard_threshold, holder_guard_max_bps);
+    
+    Ok(())
+}
+
+// =============================================================================
+// EXPIRE WITHDRAWAL (permissionless)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ExpireWithdrawal<'info> {
+    pub caller: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+}
+
+pub fn expire_withdrawal_handler(
+    ctx: Context<ExpireWithdrawal>,
+    slot: u8,
+) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    require!((slot as usize) < MAX_PENDING_WITHDRAWALS, ParadoxError::InvalidWithdrawalSlot);
+    
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    
+    let pending = &lp_lock.pending_withdrawals[slot as usize];
+    let amount = pending.amount;
+    let recipient = pending.recipient;
+    let execute_after = pending.execute_after;
+    
+    lp_lock.expire_withdrawal(slot as usize, Clock::get()?.unix_timestamp)?;
+    
+    msg!("⌛ LP Withdrawal expired (slot {})", slot);
+    msg!("   Amount: {} LP tokens | Was executable from {}", amount, execute_after);
+    
+    emit!(LpWithdrawalExpired {
+        mint: ctx.accounts.mint.key(),
+        amount,
+        recipient,
+        expired_by: ctx.accounts.caller.key(),
+        slot,
+        execute_after,
+    });
+    
+    Ok(())
+}
+
 // =============================================================================
 // GET LOCK STATUS
 // =============================================================================
@@ -513,22 +1124,92 @@ pub fn get_lock_status_handler(ctx: Context<GetLockStatus>) -> Result<()> {
     msg!("║ LP Tokens Locked: {}", lp_lock.lp_tokens_locked);
     msg!("║ Total Withdrawn: {}", lp_lock.total_withdrawn);
     msg!("║ Initial LP: {}", lp_lock.initial_lp_tokens);
+    msg!("║ Permanent floor: {} LP ({} bps)", lp_lock.liquidity_floor(), lp_lock.permanent_floor_bps);
     msg!("║ Snapshots taken: {}", lp_lock.snapshot_counter);
-    msg!("║ Pending withdrawals: {}", lp_lock.pending_count);
+    msg!("║ Pending withdrawals: {} / {}", lp_lock.pending_count, lp_lock.effective_max_pending());
+    msg!("║ Announced / Cancelled: {} / {}", lp_lock.announcements_count, lp_lock.cancellations_count);
     msg!("╚══════════════════════════════════════════════════════════════╝");
     
+    // Show snapshots with reason type
+    for snap in lp_lock.snapshots.iter().filter(|s| s.id != 0) {
+        let kind = snap.reason_kind().map_or("UNKNOWN", |k| k.name());
+        msg!("  Snapshot #{}: {} \"{}\" ({} LP, valid: {})",
+            snap.id, kind, String::from_utf8_lossy(snap.reason_note()), snap.lp_tokens, snap.is_valid);
+    }
+    
     // Show pending withdrawals
     for (i, pw) in lp_lock.pending_withdrawals.iter().enumerate() {
         if pw.is_active {
             let remaining = lp_lock.time_until_executable(i);
-            msg!("  Pending #{}: {} LP → {} ({}h remaining)",
-                i, pw.amount, pw.recipient, remaining / 3600);
+            msg!("  Pending #{}: {} LP → {} ({}h remaining, nonce {})",
+                i, pw.amount, pw.recipient, remaining / 3600, pw.execution_nonce());
         }
     }
     
     Ok(())
 }
 
+// =============================================================================
+// ADVANCE PHASE (permissionless)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct AdvancePhase<'info> {
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+}
+
+/// Persist the time-based phase and emit an event when it moves
+/// No-op if the stored phase is already current
+pub fn advance_phase_handler(ctx: Context<AdvancePhase>) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    let clock = Clock::get()?;
+    
+    match lp_lock.advance_phase(clock.unix_timestamp) {
+        Some((old_phase, new_phase)) => {
+            msg!("🔒 LP lock phase advanced: {} → {}", old_phase as u8, new_phase as u8);
+            
+            emit!(LpLockPhaseAdvanced {
+                mint: ctx.accounts.mint.key(),
+                old_phase: old_phase as u8,
+                new_phase: new_phase as u8,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        None => msg!("LP lock phase unchanged: {}", lp_lock.get_phase_name()),
+    }
+    
+    Ok(())
+}
+
+// =============================================================================
+// GET PHASE INFO (return data)
+// =============================================================================
+
+pub fn get_phase_info_handler(ctx: Context<GetLockStatus>) -> Result<PhaseInfo> {
+    Ok(ctx.accounts.lp_lock.get_phase_info())
+}
+
+// =============================================================================
+// GET PENDING WITHDRAWALS (return data)
+// =============================================================================
+
+pub fn get_pending_withdrawals_handler(ctx: Context<GetLockStatus>) -> Result<Vec<PendingWithdrawalView>> {
+    let views = ctx.accounts.lp_lock.pending_withdrawal_views_at(Clock::get()?.unix_timestamp);
+    
+    msg!("Active pending withdrawals: {}", views.len());
+    
+    Ok(views)
+}
+
 // =============================================================================
 // TRANSFER ADMIN
 // =============================================================================
@@ -554,12 +1235,171 @@ pub struct TransferAdmin<'info> {
 }
 
 pub fn transfer_admin_handler(ctx: Context<TransferAdmin>) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
     let lp_lock = &mut ctx.accounts.lp_lock;
     let old_admin = lp_lock.admin;
     
     lp_lock.admin = ctx.accounts.new_admin.key();
+    lp_lock.mark_inherited_withdrawals();
     
     msg!("Admin transferred: {} → {}", old_admin, ctx.accounts.new_admin.key());
     
+    emit!(LpLockAdminTransferred {
+        mint: ctx.accounts.mint.key(),
+        old_admin,
+        new_admin: lp_lock.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// UPDATE EMERGENCY MULTISIG
+// =============================================================================
+//
+// Rotates the emergency multisig (e.g. after a member key is compromised).
+// Either governance or the current multisig itself (i.e. its own quorum) signs.
+//
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateEmergencyMultisig<'info> {
+    #[account(
+        constraint = authority.key() == lp_lock.governance
+            || authority.key() == lp_lock.emergency_multisig @ ParadoxError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    
+    /// CHECK: New emergency multisig address
+    pub new_emergency_multisig: UncheckedAccount<'info>,
+}
+
+pub fn update_emergency_multisig_handler(ctx: Context<UpdateEmergencyMultisig>) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    let new_multisig = ctx.accounts.new_emergency_multisig.key();
+    require!(new_multisig != Pubkey::default(), ParadoxError::InvalidEmergencyMultisig);
+    
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    let old_multisig = lp_lock.emergency_multisig;
+    lp_lock.emergency_multisig = new_multisig;
+    
+    msg!("Emergency multisig rotated: {} → {}", old_multisig, new_multisig);
+    
+    emit!(LpLockEmergencyMultisigUpdated {
+        mint: ctx.accounts.mint.key(),
+        old_multisig,
+        new_multisig,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// INVALIDATE INHERITED WITHDRAWALS
+// =============================================================================
+
+/// Cancel every pending withdrawal announced before the last admin transfer
+/// Lets a new admin (e.g. a DAO) start with a clean slate. Same accounts as cancel.
+pub fn invalidate_inherited_withdrawals_handler(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    let lp_lock = &mut ctx.accounts.lp_lock;
+    let clock = Clock::get()?;
+    let mut cancelled: u8 = 0;
+    
+    for slot in 0..MAX_PENDING_WITHDRAWALS {
+        if !lp_lock.is_inherited_withdrawal(slot) {
+            continue;
+        }
+        
+        let amount = lp_lock.pending_withdrawals[slot].amount;
+        let recipient = lp_lock.pending_withdrawals[slot].recipient;
+        
+        lp_lock.cancel_withdrawal(slot, clock.unix_timestamp)?;
+        cancelled += 1;
+        
+        emit!(LpWithdrawalCancelled {
+            mint: ctx.accounts.mint.key(),
+            amount,
+            recipient,
+            cancelled_by: ctx.accounts.admin.key(),
+            slot: slot as u8,
+            announcements_count: lp_lock.announcements_count,
+            cancellations_count: lp_lock.cancellations_count,
+        });
+    }
+    
+    msg!("❌ Invalidated {} inherited LP withdrawal(s)", cancelled);
+    
+    Ok(())
+}
+
+// =============================================================================
+// CLOSE LP LOCK (reclaim rent)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct CloseLpLock<'info> {
+    #[account(
+        constraint = admin.key() == lp_lock.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        close = rent_recipient,
+        seeds = [LP_LOCK_SEED, mint.key().as_ref()],
+        bump = lp_lock.bump,
+    )]
+    pub lp_lock: Account<'info, LpLock>,
+    
+    /// CHECK: Receives reclaimed rent
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+}
+
+pub fn close_lp_lock_handler(ctx: Context<CloseLpLock>, force: bool) -> Result<()> {
+    require!(ctx.accounts.lp_lock.is_initialized(), ParadoxError::NotInitialized);
+    
+    let lp_lock = &ctx.accounts.lp_lock;
+    
+    // Only fully withdrawn locks with nothing pending can be closed
+    require!(lp_lock.can_close(), ParadoxError::LpLockNotClosable);
+    
+    // Closing discards snapshots - require explicit force to give up relaunch capability
+    require!(
+        force || !lp_lock.has_restorable_snapshot(),
+        ParadoxError::RestorableSnapshotExists
+    );
+    
+    msg!("🗑️ LP Lock closed");
+    msg!("   Total withdrawn: {}", lp_lock.total_withdrawn);
+    msg!("   Rent returned to: {}", ctx.accounts.rent_recipient.key());
+    
+    emit!(LpLockClosed {
+        mint: ctx.accounts.mint.key(),
+        closed_by: ctx.accounts.admin.key(),
+        rent_recipient: ctx.accounts.rent_recipient.key(),
+        total_withdrawn: lp_lock.total_withdrawn,
+        forced: force,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     Ok(())
 }
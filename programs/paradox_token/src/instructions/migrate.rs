@@ -0,0 +1,104 @@
+/**
+ * Account Migration Instruction
+ * 
+ * Bumps state account layout versions and populates fields carved out of
+ * reserved bytes that older accounts read as zero.
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    state::{
+        VersionedAccount, TokenConfig, LpGrowthManager, LpLock,
+        DevVestingVault, DaoTreasuryVault, ArmageddonState,
+    },
+    ParadoxError,
+    TOKEN_CONFIG_SEED,
+    CURRENT_ACCOUNT_VERSION,
+    AccountMigrated,
+};
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(
+        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    /// CHECK: Any program state account - type resolved from discriminator,
+    /// ownership checked before deserialize
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+}
+
+/// Load, version-bump and write back a single state account
+fn migrate<T>(target: &AccountInfo, expected_owner_key: Pubkey) -> Result<(u8, u8)>
+where
+    T: AccountSerialize + AccountDeserialize + VersionedAccount,
+{
+    require!(target.owner == &crate::ID, ParadoxError::InvalidAccountType);
+    
+    let mut account = T::try_deserialize(&mut &target.try_borrow_data()?[..])?;
+    
+    require!(account.owner_key() == expected_owner_key, ParadoxError::Unauthorized);
+    
+    let old_version = account.version();
+    require!(old_version < CURRENT_ACCOUNT_VERSION, ParadoxError::AlreadyMigrated);
+    
+    // Fields carved from reserved read as zero on older layouts
+    account.populate_new_fields(old_version);
+    
+    account.set_version(CURRENT_ACCOUNT_VERSION);
+    account.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])?;
+    
+    Ok((old_version, CURRENT_ACCOUNT_VERSION))
+}
+
+pub fn migrate_account_handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let target = ctx.accounts.target.to_account_info();
+    let mint = ctx.accounts.token_config.mint;
+    let config_key = ctx.accounts.token_config.key();
+    
+    let discriminator: [u8; 8] = {
+        let data = target.try_borrow_data()?;
+        require!(data.len() >= 8, ParadoxError::InvalidAccountType);
+        data[..8].try_into().map_err(|_| error!(ParadoxError::InvalidAccountType))?
+    };
+    
+    let (old_version, new_version) = if discriminator == TokenConfig::DISCRIMINATOR {
+        migrate::<TokenConfig>(&target, mint)?
+    } else if discriminator == LpGrowthManager::DISCRIMINATOR {
+        migrate::<LpGrowthManager>(&target, mint)?
+    } else if discriminator == LpLock::DISCRIMINATOR {
+        migrate::<LpLock>(&target, mint)?
+    } else if discriminator == DevVestingVault::DISCRIMINATOR {
+        migrate::<DevVestingVault>(&target, mint)?
+    } else if discriminator == DaoTreasuryVault::DISCRIMINATOR {
+        migrate::<DaoTreasuryVault>(&target, mint)?
+    } else if discriminator == ArmageddonState::DISCRIMINATOR {
+        migrate::<ArmageddonState>(&target, config_key)?
+    } else {
+        return Err(error!(ParadoxError::InvalidAccountType));
+    };
+    
+    msg!("Account {} migrated: v{} → v{}", target.key(), old_version, new_version);
+    
+    emit!(AccountMigrated {
+        account: target.key(),
+        mint,
+        old_version,
+        new_version,
+    });
+    
+    Ok(())
+}
@@ -14,6 +14,10 @@ pub mod treasury;
 pub mod armageddon;
 pub mod fees;
 pub mod harvest_fees;
+pub mod migrate;
+pub mod program_stats;
+pub mod lp_growth_meteora;
+pub mod rescue;
 
 pub use init_token_config::*;
 pub use update_token_config::*;
@@ -24,4 +28,8 @@ pub use treasury::*;
 pub use armageddon::*;
 pub use fees::*;
 pub use harvest_fees::*;
+pub use migrate::*;
+pub use program_stats::*;
+pub use lp_growth_meteora::*;
+pub use rescue::*;
 
@@ -0,0 +1,79 @@
+/**
+ * Program Stats Instructions
+ * 
+ * Per-deployer aggregate analytics. Other handlers update ProgramStats
+ * directly (no CPI) when it is passed as an optional account.
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{ProgramStats, ProgramStatsSummary},
+    PROGRAM_STATS_SEED,
+    CURRENT_ACCOUNT_VERSION,
+};
+
+// =============================================================================
+// INIT PROGRAM STATS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitProgramStats<'info> {
+    #[account(mut)]
+    pub deployer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = deployer,
+        space = ProgramStats::LEN,
+        seeds = [PROGRAM_STATS_SEED, deployer.key().as_ref()],
+        bump,
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_program_stats_handler(ctx: Context<InitProgramStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.program_stats;
+    
+    stats.deployer = ctx.accounts.deployer.key();
+    stats.tokens_launched = 0;
+    stats.total_fees_harvested = 0;
+    stats.total_burned = 0;
+    stats.total_lp_grown = 0;
+    stats.bump = ctx.bumps.program_stats;
+    stats.version = CURRENT_ACCOUNT_VERSION;
+    
+    msg!("Program stats initialized for deployer {}", stats.deployer);
+    Ok(())
+}
+
+// =============================================================================
+// GET PROGRAM STATS (return data)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct GetProgramStats<'info> {
+    #[account(
+        seeds = [PROGRAM_STATS_SEED, program_stats.deployer.as_ref()],
+        bump = program_stats.bump,
+    )]
+    pub program_stats: Account<'info, ProgramStats>,
+}
+
+pub fn get_program_stats_handler(ctx: Context<GetProgramStats>) -> Result<ProgramStatsSummary> {
+    let summary = ctx.accounts.program_stats.summary();
+    
+    msg!("Deployer {}: {} tokens | harvested {} | burned {} | LP grown {}",
+         summary.deployer,
+         summary.tokens_launched,
+         summary.total_fees_harvested,
+         summary.total_burned,
+         summary.total_lp_grown);
+    
+    Ok(summary)
+}
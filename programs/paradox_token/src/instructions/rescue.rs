@@ -0,0 +1,176 @@
+/**
+ * Foreign Token Rescue
+ * 
+ * Tokens of an unrelated mint sent to an account owned by one of our vault
+ * PDAs are otherwise stuck - only the PDA can sign for them. The admin can
+ * move them out to any recipient. The configured mint (and the LP token mint
+ * for the LP lock) can never be moved this way.
+ * 
+ * VAULTS (vault_authority PDA that owns the stuck account):
+ * - 0 FeeVault:  [HARVEST_AUTHORITY_SEED, mint]
+ * - 1 Treasury:  [DAO_TREASURY_SEED, mint]
+ * - 2 LpLock:    [LP_LOCK_SEED, mint] (lp_lock account required)
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    TokenInterface, TokenAccount, Mint,
+    TransferChecked, transfer_checked,
+};
+
+use crate::{
+    state::{TokenConfig, LpLock},
+    instructions::harvest_fees::HARVEST_AUTHORITY_SEED,
+    ParadoxError,
+    TOKEN_CONFIG_SEED,
+    DAO_TREASURY_SEED,
+    LP_LOCK_SEED,
+    ForeignTokensRescued,
+};
+
+/// Vault PDA a rescue signs for (passed as u8)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RescueVault {
+    FeeVault = 0,
+    Treasury = 1,
+    LpLock = 2,
+}
+
+impl RescueVault {
+    pub fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0 => Some(Self::FeeVault),
+            1 => Some(Self::Treasury),
+            2 => Some(Self::LpLock),
+            _ => None,
+        }
+    }
+    
+    pub fn seed(&self) -> &'static [u8] {
+        match self {
+            Self::FeeVault => HARVEST_AUTHORITY_SEED,
+            Self::Treasury => DAO_TREASURY_SEED,
+            Self::LpLock => LP_LOCK_SEED,
+        }
+    }
+}
+
+// =============================================================================
+// RESCUE FOREIGN TOKENS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct RescueForeignTokens<'info> {
+    #[account(
+        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    /// The configured mint - never rescuable
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, mint.key().as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    /// CHECK: Vault PDA owning the stuck account - derived and checked in the handler
+    pub vault_authority: UncheckedAccount<'info>,
+    
+    /// Required for RescueVault::LpLock (its LP token mint is protected too)
+    pub lp_lock: Option<Account<'info, LpLock>>,
+    
+    #[account(
+        constraint = foreign_mint.key() != mint.key() @ ParadoxError::CannotRescueConfiguredMint,
+    )]
+    pub foreign_mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        constraint = source.mint == foreign_mint.key() @ ParadoxError::InvalidVault,
+        constraint = source.owner == vault_authority.key() @ ParadoxError::InvalidVault,
+    )]
+    pub source: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == foreign_mint.key() @ ParadoxError::InvalidRecipientAta,
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Token program of the foreign mint (SPL Token or Token-2022)
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn rescue_foreign_tokens_handler(
+    ctx: Context<RescueForeignTokens>,
+    vault: u8,
+    amount: u64,
+) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
+    let vault = RescueVault::from_byte(vault).ok_or(ParadoxError::InvalidRescueVault)?;
+    
+    require!(amount > 0, ParadoxError::InsufficientVaultBalance);
+    require!(amount <= ctx.accounts.source.amount, ParadoxError::InsufficientVaultBalance);
+    
+    // The signer must be the PDA for the chosen vault
+    let mint_key = ctx.accounts.mint.key();
+    let (expected_authority, bump) = Pubkey::find_program_address(
+        &[vault.seed(), mint_key.as_ref()],
+        ctx.program_id,
+    );
+    require!(
+        ctx.accounts.vault_authority.key() == expected_authority,
+        ParadoxError::InvalidRescueVault
+    );
+    
+    // LP tokens are the lock's legitimate holdings
+    if vault == RescueVault::LpLock {
+        let lp_lock = ctx.accounts.lp_lock.as_ref().ok_or(ParadoxError::InvalidRescueVault)?;
+        require!(lp_lock.key() == expected_authority, ParadoxError::InvalidRescueVault);
+        require!(
+            ctx.accounts.foreign_mint.key() != lp_lock.lp_token_mint,
+            ParadoxError::CannotRescueConfiguredMint
+        );
+    }
+    
+    let seeds: &[&[u8]] = &[
+        vault.seed(),
+        mint_key.as_ref(),
+        &[bump],
+    ];
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+                mint: ctx.accounts.foreign_mint.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+        ctx.accounts.foreign_mint.decimals,
+    )?;
+    
+    emit!(ForeignTokensRescued {
+        mint: mint_key,
+        vault: vault as u8,
+        foreign_mint: ctx.accounts.foreign_mint.key(),
+        amount,
+        recipient: ctx.accounts.recipient_token_account.key(),
+        rescued_by: ctx.accounts.admin.key(),
+    });
+    
+    msg!("Rescued {} foreign tokens ({}) from vault {}",
+         amount, ctx.accounts.foreign_mint.key(), vault as u8);
+    
+    Ok(())
+}
@@ -11,21 +11,37 @@ use anchor_spl::token_interface::{
     TransferChecked, transfer_checked,
     InterfaceAccount, Interface,
 };
+use anchor_spl::associated_token::{
+    AssociatedToken, Create, create_idempotent,
+    get_associated_token_address_with_program_id,
+};
 
 use crate::{
-    state::DaoTreasuryVault,
+    state::{DaoTreasuryVault, TreasuryStatus, TokenConfig, TreasuryAllowlist, MAX_TREASURY_APPROVERS, MAX_TREASURY_RECIPIENTS},
     ParadoxError,
-    MIN_TRANSFER_AMOUNT,
+    TOKEN_CONFIG_SEED,
+    TREASURY_ALLOWLIST_SEED,
+    MIN_TREASURY_TIMELOCK_SECONDS,
+    CURRENT_ACCOUNT_VERSION,
     DaoWithdrawalProposed,
     DaoWithdrawalExecuted,
+    DaoWithdrawalCancelled,
+    DaoTreasuryInitialized,
+    TreasuryTimelockUpdated,
+    TreasuryDeposited,
+    TreasuryGovernanceTransferStarted,
+    TreasuryGovernanceTransferred,
+    TreasuryApprovalsConfigured,
+    DaoWithdrawalApproved,
+    PayoutFeeModeUpdated,
+    TreasuryAllowlistUpdated,
+    fee_mode::{FeeMode, payout_amounts},
+    transfer_hook::transfer_checked_with_hook,
 };
 
 /// Seed for DAO Treasury PDA
 pub const DAO_TREASURY_SEED: &[u8] = b"dao_treasury";
 
-/// Token decimals (9 for PDOX - matches deployed mint)
-const TOKEN_DECIMALS: u8 = 9;
-
 // =============================================================================
 // INIT DAO TREASURY
 // =============================================================================
@@ -57,7 +73,13 @@ pub fn init_handler(
     governance: Pubkey,
     max_spend_bps_per_period: u16,
     period_seconds: i64,
+    timelock_seconds: i64,
 ) -> Result<()> {
+    require!(
+        timelock_seconds >= MIN_TREASURY_TIMELOCK_SECONDS,
+        ParadoxError::TimelockTooShort
+    );
+    
     let treasury = &mut ctx.accounts.treasury;
     let clock = Clock::get()?;
     
@@ -69,15 +91,295 @@ pub fn init_handler(
     treasury.period_seconds = period_seconds;
     treasury.period_start = clock.unix_timestamp;
     treasury.spent_this_period = 0;
+    treasury.reserved_this_period = 0;
+    treasury.allowlist_enabled = false;
     treasury.pending_amount = 0;
     treasury.pending_recipient = Pubkey::default();
     treasury.pending_reason = [0u8; 128];
     treasury.pending_execute_after = 0;
-    treasury.timelock_seconds = 48 * 60 * 60; // 48h default
+    treasury.timelock_seconds = timelock_seconds;
     treasury.total_withdrawn = 0;
     treasury.bump = ctx.bumps.treasury;
+    treasury.is_initialized = true;
+    treasury.version = CURRENT_ACCOUNT_VERSION;
+    treasury.proposal_nonce = 0;
+    treasury.fee_mode = FeeMode::Net as u8;
+    treasury.pending_governance = Pubkey::default();
+    treasury.large_withdrawal_threshold = 0;
+    treasury.required_approvals = 0;
+    treasury.approvers = [Pubkey::default(); MAX_TREASURY_APPROVERS];
+    treasury.pending_approvals = 0;
     
     msg!("DAO Treasury initialized with governance: {}", governance);
+    
+    emit!(DaoTreasuryInitialized {
+        governance,
+        mint: treasury.mint,
+        max_spend_bps_per_period,
+        period_seconds,
+        timelock_seconds: treasury.timelock_seconds,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// UPDATE TREASURY TIMELOCK (lengthen only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateTreasuryTimelock<'info> {
+    #[account(
+        constraint = governance.key() == treasury.governance @ ParadoxError::Unauthorized
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn update_timelock_handler(
+    ctx: Context<UpdateTreasuryTimelock>,
+    new_timelock_seconds: i64,
+) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    let treasury = &mut ctx.accounts.treasury;
+    
+    // SECURITY: Timelock can never be shortened
+    require!(
+        new_timelock_seconds >= treasury.timelock_seconds,
+        ParadoxError::TimelockCannotDecrease
+    );
+    
+    let old_timelock_seconds = treasury.timelock_seconds;
+    treasury.timelock_seconds = new_timelock_seconds;
+    
+    emit!(TreasuryTimelockUpdated {
+        mint: treasury.mint,
+        old_timelock_seconds,
+        new_timelock_seconds,
+    });
+    
+    msg!("Treasury timelock updated: {}s → {}s", old_timelock_seconds, new_timelock_seconds);
+    
+    Ok(())
+}
+
+// =============================================================================
+// SET TREASURY FEE MODE
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetTreasuryFeeMode<'info> {
+    #[account(
+        constraint = governance.key() == treasury.governance @ ParadoxError::Unauthorized
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn set_fee_mode_handler(ctx: Context<SetTreasuryFeeMode>, fee_mode: u8) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    let mode = FeeMode::from_byte(fee_mode).ok_or(ParadoxError::InvalidFeeMode)?;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    let old_mode = treasury.fee_mode;
+    treasury.fee_mode = fee_mode;
+    
+    emit!(PayoutFeeModeUpdated {
+        vault: treasury.key(),
+        mint: treasury.mint,
+        old_mode,
+        new_mode: fee_mode,
+    });
+    
+    msg!("Treasury fee mode: {}", mode.name());
+    
+    Ok(())
+}
+
+// =============================================================================
+// TRANSFER TREASURY GOVERNANCE (two-step)
+// =============================================================================
+//
+// Step 1: current governance proposes a new key
+// Step 2: new key signs accept_treasury_governance
+//
+// Prevents bricking the treasury with a typo'd or non-signing address.
+//
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct TransferTreasuryGovernance<'info> {
+    #[account(
+        constraint = governance.key() == treasury.governance @ ParadoxError::Unauthorized
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn transfer_governance_handler(
+    ctx: Context<TransferTreasuryGovernance>,
+    new_governance: Pubkey,
+) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    let treasury = &mut ctx.accounts.treasury;
+    
+    // Pubkey::default() cancels a pending transfer
+    treasury.pending_governance = new_governance;
+    
+    emit!(TreasuryGovernanceTransferStarted {
+        mint: treasury.mint,
+        current_governance: treasury.governance,
+        pending_governance: new_governance,
+    });
+    
+    msg!("Treasury governance transfer proposed: {} → {}", treasury.governance, new_governance);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptTreasuryGovernance<'info> {
+    #[account(
+        constraint = treasury.pending_governance != Pubkey::default() @ ParadoxError::Unauthorized,
+        constraint = new_governance.key() == treasury.pending_governance @ ParadoxError::Unauthorized,
+    )]
+    pub new_governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn accept_governance_handler(ctx: Context<AcceptTreasuryGovernance>) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    let treasury = &mut ctx.accounts.treasury;
+    let old_governance = treasury.governance;
+    
+    treasury.governance = treasury.pending_governance;
+    treasury.pending_governance = Pubkey::default();
+    
+    emit!(TreasuryGovernanceTransferred {
+        mint: treasury.mint,
+        old_governance,
+        new_governance: treasury.governance,
+    });
+    
+    msg!("Treasury governance transferred: {} → {}", old_governance, treasury.governance);
+    
+    Ok(())
+}
+
+// =============================================================================
+// DEPOSIT TO TREASURY
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct DepositToTreasury<'info> {
+    pub depositor: Signer<'info>,
+    
+    #[account(
+        constraint = mint.key() == treasury.mint @ ParadoxError::InvalidVault,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+    
+    /// Per-mint dust floor
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, treasury.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    #[account(
+        mut,
+        constraint = treasury_token_account.key() == treasury.token_account @ ParadoxError::InvalidVault,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Depositor's token account
+    #[account(
+        mut,
+        constraint = source_token_account.owner == depositor.key() @ ParadoxError::Unauthorized,
+    )]
+    pub source_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn deposit_handler(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    require!(
+        amount >= ctx.accounts.token_config.effective_min_transfer_amount(),
+        ParadoxError::AmountBelowMinimum
+    );
+    
+    let balance_before = ctx.accounts.treasury_token_account.amount;
+    
+    transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.source_token_account.to_account_info(),
+                to: ctx.accounts.treasury_token_account.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    // Credit what actually arrived (Token-2022 transfer fee is withheld)
+    ctx.accounts.treasury_token_account.reload()?;
+    let received = ctx.accounts.treasury_token_account.amount
+        .checked_sub(balance_before)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.balance = treasury.balance
+        .checked_add(received)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    emit!(TreasuryDeposited {
+        mint: treasury.mint,
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        received,
+        balance: treasury.balance,
+    });
+    
+    msg!("Treasury deposit: {} tokens (balance: {})", received, treasury.balance);
+    
     Ok(())
 }
 
@@ -98,6 +400,26 @@ pub struct ProposeDaoWithdrawal<'info> {
         bump = treasury.bump,
     )]
     pub treasury: Account<'info, DaoTreasuryVault>,
+    
+    /// Per-mint dust floor
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, treasury.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+    
+    /// Transfer fee config for the gross payout
+    #[account(
+        constraint = mint.key() == treasury.mint @ ParadoxError::InvalidVault,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// Required once the allowlist is enabled
+    #[account(
+        seeds = [TREASURY_ALLOWLIST_SEED, treasury.mint.as_ref()],
+        bump = treasury_allowlist.bump,
+    )]
+    pub treasury_allowlist: Option<Account<'info, TreasuryAllowlist>>,
 }
 
 pub fn propose_handler(
@@ -106,41 +428,293 @@ pub fn propose_handler(
     recipient: Pubkey,
     reason: String,
 ) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    let min_transfer_amount = ctx.accounts.token_config.effective_min_transfer_amount();
     let treasury = &mut ctx.accounts.treasury;
     let clock = Clock::get()?;
     
     // SECURITY: Enforce minimum transfer amount (dust attack prevention)
-    require!(amount >= MIN_TRANSFER_AMOUNT, ParadoxError::AmountBelowMinimum);
+    require!(amount >= min_transfer_amount, ParadoxError::AmountBelowMinimum);
+    
+    // No silent overwrite: cancel the pending proposal first
+    require!(!treasury.has_pending_withdrawal(), ParadoxError::WithdrawalAlreadyPending);
     
-    // Reset period if needed
+    // SECURITY: With the allowlist on, governance can only pay approved recipients
+    if treasury.allowlist_enabled {
+        let allowlist = ctx.accounts.treasury_allowlist
+            .as_ref()
+            .ok_or(ParadoxError::RecipientNotAllowlisted)?;
+        require!(allowlist.contains(&recipient), ParadoxError::RecipientNotAllowlisted);
+    }
+    
+    // Reset period if needed (snaps to aligned boundary)
     if treasury.should_reset_period(clock.unix_timestamp) {
+        msg!("Spending period reset ({} periods elapsed)", treasury.elapsed_periods(clock.unix_timestamp));
         treasury.reset_period(clock.unix_timestamp);
     }
     
-    // Check spending limit
-    require!(amount <= treasury.max_spendable(), ParadoxError::DaoSpendingLimitExceeded);
+    // Gross is what execute books as spent - cap and reserve that, not the request
+    let fee_mode = FeeMode::from_byte(treasury.fee_mode).ok_or(ParadoxError::InvalidFeeMode)?;
+    let (gross_amount, _) = payout_amounts(&ctx.accounts.mint.to_account_info(), amount, fee_mode)?;
+    
+    // Check spending limit (net of spent and already-reserved amounts)
+    require!(gross_amount <= treasury.max_spendable(), ParadoxError::DaoSpendingLimitExceeded);
+    
+    // Hold the amount against the period budget until execute or cancel
+    treasury.reserve(gross_amount)?;
+    treasury.pending_reserved = gross_amount;
     
-    // Set pending withdrawal
+    // Set pending withdrawal (new proposal starts with no approvals)
     treasury.pending_amount = amount;
     treasury.pending_recipient = recipient;
+    treasury.pending_approvals = 0;
     
-    // Copy reason (truncate if needed)
+    // Copy reason (reject rather than truncate mid-character)
     let reason_bytes = reason.as_bytes();
-    let copy_len = reason_bytes.len().min(128);
-    treasury.pending_reason[..copy_len].copy_from_slice(&reason_bytes[..copy_len]);
+    require!(
+        reason_bytes.len() <= treasury.pending_reason.len(),
+        ParadoxError::InvalidReason
+    );
+    treasury.pending_reason = [0u8; 128];
+    treasury.pending_reason[..reason_bytes.len()].copy_from_slice(reason_bytes);
     
     treasury.pending_execute_after = clock.unix_timestamp
         .checked_add(treasury.timelock_seconds)
         .ok_or(ParadoxError::MathOverflow)?;
     
+    treasury.proposal_nonce = treasury.proposal_nonce
+        .checked_add(1)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
     emit!(DaoWithdrawalProposed {
         proposer: ctx.accounts.governance.key(),
         amount,
         recipient,
         reason,
         execute_after: treasury.pending_execute_after,
+        proposal_nonce: treasury.proposal_nonce,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// RECIPIENT ALLOWLIST
+// =============================================================================
+//
+// Optional. Once governance enables it, only allowlist_admin (a separate,
+// higher key) can change who the treasury may pay. There is no disable: a
+// compromised governance key must not be able to switch it off.
+//
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct InitTreasuryAllowlist<'info> {
+    #[account(
+        mut,
+        constraint = governance.key() == treasury.governance @ ParadoxError::Unauthorized
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+    
+    #[account(
+        init,
+        payer = governance,
+        space = TreasuryAllowlist::LEN,
+        seeds = [TREASURY_ALLOWLIST_SEED, treasury.mint.as_ref()],
+        bump,
+    )]
+    pub treasury_allowlist: Account<'info, TreasuryAllowlist>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_allowlist_handler(
+    ctx: Context<InitTreasuryAllowlist>,
+    allowlist_admin: Pubkey,
+) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    require!(allowlist_admin != Pubkey::default(), ParadoxError::Unauthorized);
+    
+    let allowlist = &mut ctx.accounts.treasury_allowlist;
+    allowlist.treasury = ctx.accounts.treasury.key();
+    allowlist.allowlist_admin = allowlist_admin;
+    allowlist.recipients = [Pubkey::default(); MAX_TREASURY_RECIPIENTS];
+    allowlist.bump = ctx.bumps.treasury_allowlist;
+    allowlist.version = CURRENT_ACCOUNT_VERSION;
+    
+    ctx.accounts.treasury.allowlist_enabled = true;
+    
+    msg!("Treasury allowlist enabled (admin: {})", allowlist_admin);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTreasuryAllowlist<'info> {
+    #[account(
+        constraint = allowlist_admin.key() == treasury_allowlist.allowlist_admin @ ParadoxError::Unauthorized
+    )]
+    pub allowlist_admin: Signer<'info>,
+    
+    #[account(
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_ALLOWLIST_SEED, treasury.mint.as_ref()],
+        bump = treasury_allowlist.bump,
+        constraint = treasury_allowlist.treasury == treasury.key() @ ParadoxError::Unauthorized,
+    )]
+    pub treasury_allowlist: Account<'info, TreasuryAllowlist>,
+}
+
+pub fn add_recipient_handler(ctx: Context<UpdateTreasuryAllowlist>, recipient: Pubkey) -> Result<()> {
+    let allowlist = &mut ctx.accounts.treasury_allowlist;
+    allowlist.add(recipient)?;
+    
+    emit!(TreasuryAllowlistUpdated {
+        mint: ctx.accounts.treasury.mint,
+        recipient,
+        added: true,
+        count: allowlist.count() as u8,
+    });
+    
+    Ok(())
+}
+
+/// Removing doesn't touch a pending proposal - governance cancels it if needed
+pub fn remove_recipient_handler(ctx: Context<UpdateTreasuryAllowlist>, recipient: Pubkey) -> Result<()> {
+    let allowlist = &mut ctx.accounts.treasury_allowlist;
+    allowlist.remove(&recipient)?;
+    
+    emit!(TreasuryAllowlistUpdated {
+        mint: ctx.accounts.treasury.mint,
+        recipient,
+        added: false,
+        count: allowlist.count() as u8,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// LARGE WITHDRAWAL APPROVALS
+// =============================================================================
+//
+// Below large_withdrawal_threshold: single governance flow (propose → execute).
+// Above it: N distinct approvers must also sign approve_dao_withdrawal before
+// execute. Approvals are tied to the pending proposal and reset on execute/cancel.
+//
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct ConfigureTreasuryApprovals<'info> {
+    #[account(
+        constraint = governance.key() == treasury.governance @ ParadoxError::Unauthorized
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn configure_approvals_handler(
+    ctx: Context<ConfigureTreasuryApprovals>,
+    large_withdrawal_threshold: u64,
+    required_approvals: u8,
+    approvers: Vec<Pubkey>,
+) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    // Validate approver set: bounded, distinct, non-default, enough to reach quorum
+    require!(approvers.len() <= MAX_TREASURY_APPROVERS, ParadoxError::InvalidApprovers);
+    for (i, a) in approvers.iter().enumerate() {
+        require!(*a != Pubkey::default(), ParadoxError::InvalidApprovers);
+        require!(!approvers[..i].contains(a), ParadoxError::InvalidApprovers);
+    }
+    if large_withdrawal_threshold > 0 {
+        require!(
+            required_approvals > 0 && (required_approvals as usize) <= approvers.len(),
+            ParadoxError::InvalidApprovers
+        );
+    }
+    
+    let treasury = &mut ctx.accounts.treasury;
+    
+    treasury.large_withdrawal_threshold = large_withdrawal_threshold;
+    treasury.required_approvals = required_approvals;
+    treasury.approvers = [Pubkey::default(); MAX_TREASURY_APPROVERS];
+    treasury.approvers[..approvers.len()].copy_from_slice(&approvers);
+    
+    // Approver indices changed - existing approvals no longer meaningful
+    treasury.pending_approvals = 0;
+    
+    emit!(TreasuryApprovalsConfigured {
+        mint: treasury.mint,
+        large_withdrawal_threshold,
+        required_approvals,
+        approvers,
     });
     
+    msg!("Treasury approvals: {} of {} above {} tokens",
+         required_approvals, treasury.approvers.iter().filter(|a| **a != Pubkey::default()).count(),
+         large_withdrawal_threshold);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveDaoWithdrawal<'info> {
+    pub approver: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn approve_handler(ctx: Context<ApproveDaoWithdrawal>) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    let treasury = &mut ctx.accounts.treasury;
+    let approver = ctx.accounts.approver.key();
+    
+    require!(treasury.pending_amount > 0, ParadoxError::NoActiveWithdrawal);
+    
+    let index = treasury.approver_index(&approver).ok_or(ParadoxError::Unauthorized)?;
+    let bit = 1u8 << index;
+    require!(treasury.pending_approvals & bit == 0, ParadoxError::AlreadyApproved);
+    
+    treasury.pending_approvals |= bit;
+    
+    emit!(DaoWithdrawalApproved {
+        mint: treasury.mint,
+        approver,
+        amount: treasury.pending_amount,
+        approvals: treasury.approval_count(),
+        required_approvals: treasury.required_approvals,
+    });
+    
+    msg!("DAO withdrawal approved by {} ({}/{})",
+         approver, treasury.approval_count(), treasury.required_approvals);
+    
     Ok(())
 }
 
@@ -150,6 +724,8 @@ pub fn propose_handler(
 
 #[derive(Accounts)]
 pub struct ExecuteDaoWithdrawal<'info> {
+    /// Pays rent if the recipient's ATA has to be created
+    #[account(mut)]
     pub executor: Signer<'info>,
     
     pub mint: InterfaceAccount<'info, Mint>,
@@ -168,25 +744,154 @@ pub struct ExecuteDaoWithdrawal<'info> {
     pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// Recipient's token account - owner must match pending_recipient
+    /// If it doesn't exist yet it must be pending_recipient's ATA and is created here
+    /// CHECK: Validated in handler (may be uninitialized)
+    #[account(mut)]
+    pub recipient_token_account: UncheckedAccount<'info>,
+    
+    /// Recipient wallet - only needed to create the ATA
+    /// CHECK: Must be pending_recipient
     #[account(
-        mut,
-        constraint = recipient_token_account.owner == treasury.pending_recipient @ ParadoxError::Unauthorized,
+        constraint = recipient.key() == treasury.pending_recipient @ ParadoxError::Unauthorized,
     )]
-    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub recipient: Option<UncheckedAccount<'info>>,
     
     pub token_program: Interface<'info, TokenInterface>,
+    
+    /// Only needed to create the ATA
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+    
+    /// Only needed to create the ATA
+    pub system_program: Option<Program<'info, System>>,
 }
 
-pub fn execute_handler(ctx: Context<ExecuteDaoWithdrawal>) -> Result<()> {
-    let treasury = &mut ctx.accounts.treasury;
+/// Create pending_recipient's ATA when the recipient account doesn't exist yet
+/// Returns true if the account was created
+fn create_recipient_ata_if_missing(ctx: &Context<ExecuteDaoWithdrawal>) -> Result<bool> {
+    let accounts = &ctx.accounts;
+    
+    if !accounts.recipient_token_account.data_is_empty() {
+        return Ok(false);
+    }
+    
+    let expected = get_associated_token_address_with_program_id(
+        &accounts.treasury.pending_recipient,
+        &accounts.mint.key(),
+        &accounts.token_program.key(),
+    );
+    require!(
+        accounts.recipient_token_account.key() == expected,
+        ParadoxError::InvalidRecipientAta
+    );
+    
+    let (recipient, ata_program, system_program) = match (
+        &accounts.recipient,
+        &accounts.associated_token_program,
+        &accounts.system_program,
+    ) {
+        (Some(r), Some(a), Some(s)) => (r, a, s),
+        _ => return err!(ParadoxError::RecipientAtaAccountsMissing),
+    };
+    
+    create_idempotent(CpiContext::new(
+        ata_program.to_account_info(),
+        Create {
+            payer: accounts.executor.to_account_info(),
+            associated_token: accounts.recipient_token_account.to_account_info(),
+            authority: recipient.to_account_info(),
+            mint: accounts.mint.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: accounts.token_program.to_account_info(),
+        },
+    ))?;
+    
+    Ok(true)
+}
+
+pub fn execute_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteDaoWithdrawal<'info>>,
+    proposal_nonce: u64,
+) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    // Must be executing the proposal the caller reviewed, not a superseded one
+    require!(
+        ctx.accounts.treasury.proposal_nonce == proposal_nonce,
+        ParadoxError::StaleWithdrawalNonce
+    );
+    
     let clock = Clock::get()?;
     
     // Check timelock
-    require!(treasury.can_execute_withdrawal(clock.unix_timestamp), ParadoxError::TimelockNotExpired);
+    require!(ctx.accounts.treasury.can_execute_withdrawal(clock.unix_timestamp), ParadoxError::TimelockNotExpired);
+    
+    // Large withdrawals need N distinct approver signatures
+    require!(ctx.accounts.treasury.has_required_approvals(), ParadoxError::InsufficientApprovals);
+    
+    // External grantees often have no ATA yet - create it (executor pays rent)
+    if create_recipient_ata_if_missing(&ctx)? {
+        msg!("Created recipient ATA {}", ctx.accounts.recipient_token_account.key());
+    }
+    
+    // Works for both pre-existing and freshly created accounts
+    require!(
+        ctx.accounts.recipient_token_account.owner == &ctx.accounts.token_program.key(),
+        ParadoxError::InvalidRecipientAta
+    );
+    let recipient_token_account = {
+        let data = ctx.accounts.recipient_token_account.try_borrow_data()?;
+        TokenAccount::try_deserialize(&mut &data[..])?
+    };
+    require!(
+        recipient_token_account.owner == ctx.accounts.treasury.pending_recipient,
+        ParadoxError::Unauthorized
+    );
+    require!(
+        recipient_token_account.mint == ctx.accounts.mint.key(),
+        ParadoxError::InvalidRecipientAta
+    );
+    
+    let treasury = &mut ctx.accounts.treasury;
     
     let amount = treasury.pending_amount;
     let recipient = treasury.pending_recipient;
     
+    let fee_mode = FeeMode::from_byte(treasury.fee_mode).ok_or(ParadoxError::InvalidFeeMode)?;
+    let (gross_amount, net_amount) = payout_amounts(
+        &ctx.accounts.mint.to_account_info(),
+        amount,
+        fee_mode,
+    )?;
+    
+    // Check against the live token balance, not the cached `balance` (which can drift)
+    ctx.accounts.treasury_token_account.reload()?;
+    require!(
+        amount <= ctx.accounts.treasury_token_account.amount,
+        ParadoxError::InsufficientVaultBalance
+    );
+    require!(
+        ctx.accounts.treasury_token_account.amount >= gross_amount,
+        ParadoxError::FeeGrossUpNotCovered
+    );
+    
+    // Effects before interactions: record the spend and clear the proposal, then
+    // persist before the CPI (a failed transfer reverts the whole transaction)
+    if treasury.should_reset_period(clock.unix_timestamp) {
+        treasury.reset_period(clock.unix_timestamp);
+    }
+    
+    // Gross is what actually leaves the treasury (checked arithmetic)
+    treasury.spent_this_period = treasury.spent_this_period
+        .checked_add(gross_amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    treasury.total_withdrawn = treasury.total_withdrawn
+        .checked_add(gross_amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    // Clear pending (releases the reservation)
+    treasury.clear_pending();
+    treasury.exit(ctx.program_id)?;
+    
     // Transfer tokens (uses transfer_checked for Token-2022 fee compliance)
     let mint_key = treasury.mint;
     let seeds: &[&[u8]] = &[
@@ -195,40 +900,112 @@ pub fn execute_handler(ctx: Context<ExecuteDaoWithdrawal>) -> Result<()> {
         &[treasury.bump],
     ];
     
-    transfer_checked(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.treasury_token_account.to_account_info(),
-                to: ctx.accounts.recipient_token_account.to_account_info(),
-                authority: treasury.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-            },
-            &[seeds],
-        ),
-        amount,
-        TOKEN_DECIMALS,
+    transfer_checked_with_hook(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.treasury_token_account.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.recipient_token_account.to_account_info(),
+        &treasury.to_account_info(),
+        ctx.remaining_accounts,
+        gross_amount,
+        ctx.accounts.mint.decimals,
+        &[seeds],
     )?;
     
-    // Update state (checked arithmetic)
-    treasury.spent_this_period = treasury.spent_this_period
-        .checked_add(amount)
-        .ok_or(ParadoxError::MathOverflow)?;
-    treasury.total_withdrawn = treasury.total_withdrawn
-        .checked_add(amount)
-        .ok_or(ParadoxError::MathOverflow)?;
-    treasury.balance = treasury.balance.saturating_sub(amount);
-    
-    // Clear pending
-    treasury.pending_amount = 0;
-    treasury.pending_recipient = Pubkey::default();
-    treasury.pending_reason = [0u8; 128];
-    treasury.pending_execute_after = 0;
+    // Resync cached balance from the vault instead of subtracting (no silent drift)
+    ctx.accounts.treasury_token_account.reload()?;
+    treasury.balance = ctx.accounts.treasury_token_account.amount;
     
     emit!(DaoWithdrawalExecuted {
         recipient,
         amount,
+        gross_amount,
+        net_amount,
+        proposal_nonce,
     });
     
     Ok(())
 }
+
+// =============================================================================
+// CANCEL DAO WITHDRAWAL
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct CancelDaoWithdrawal<'info> {
+    #[account(
+        constraint = governance.key() == treasury.governance @ ParadoxError::Unauthorized
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn cancel_handler(ctx: Context<CancelDaoWithdrawal>, proposal_nonce: u64) -> Result<()> {
+    require!(ctx.accounts.treasury.is_initialized(), ParadoxError::NotInitialized);
+    
+    let treasury = &mut ctx.accounts.treasury;
+    
+    require!(treasury.has_pending_withdrawal(), ParadoxError::NoActiveWithdrawal);
+    require!(treasury.proposal_nonce == proposal_nonce, ParadoxError::StaleWithdrawalNonce);
+    
+    let amount = treasury.pending_amount;
+    let recipient = treasury.pending_recipient;
+    
+    treasury.clear_pending();
+    
+    emit!(DaoWithdrawalCancelled {
+        recipient,
+        amount,
+        proposal_nonce,
+    });
+    
+    msg!("DAO withdrawal #{} cancelled", proposal_nonce);
+    
+    Ok(())
+}
+
+// =============================================================================
+// GET TREASURY STATUS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct GetTreasuryStatus<'info> {
+    #[account(
+        seeds = [DAO_TREASURY_SEED, treasury.mint.as_ref()],
+        bump = treasury.bump,
+    )]
+    pub treasury: Account<'info, DaoTreasuryVault>,
+}
+
+pub fn get_status_handler(ctx: Context<GetTreasuryStatus>) -> Result<TreasuryStatus> {
+    let treasury = &ctx.accounts.treasury;
+    let clock = Clock::get()?;
+    let status = treasury.get_status(clock.unix_timestamp);
+    
+    msg!("╔══════════════════════════════════════════════════════════════╗");
+    msg!("║           DAO TREASURY STATUS                                ║");
+    msg!("╠══════════════════════════════════════════════════════════════╣");
+    msg!("║ Balance: {}", status.balance);
+    msg!("║ Spent this period: {} (reserved: {})", status.spent_this_period, status.reserved_this_period);
+    msg!("║ Spendable now: {}", status.max_spendable);
+    msg!("║ Next period reset: {}", status.next_period_reset);
+    msg!("║ Periods elapsed since last reset: {}", treasury.elapsed_periods(clock.unix_timestamp));
+    if status.pending_amount > 0 {
+        msg!("║ Pending: {} → {}", status.pending_amount, status.pending_recipient);
+        msg!("║ Executable after: {}", status.pending_execute_after);
+        if status.required_approvals > 0 {
+            msg!("║ Approvals: {}/{}", status.pending_approvals, status.required_approvals);
+        }
+    } else {
+        msg!("║ Pending: none");
+    }
+    msg!("╚══════════════════════════════════════════════════════════════╝");
+    
+    Ok(status)
+}
@@ -10,15 +10,15 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    state::TokenConfig,
+    state::{TokenConfig, ConfigSummary, FeeChangeStatus},
     ParadoxError,
     TOKEN_CONFIG_SEED,
-    MIN_TRANSFER_FEE_BPS,
-    MAX_TRANSFER_FEE_BPS,
-    FEE_CHANGE_TIMELOCK_SECONDS,
+    MAX_HARVESTER_REWARD_BPS,
     FeeChangeAnnounced,
     TransferFeeUpdated,
     FeeChangeCancelled,
+    FeeSharesUpdated,
+    HarvesterRewardUpdated,
 };
 
 // =============================================================================
@@ -43,29 +43,12 @@ pub fn announce_fee_change_handler(
     ctx: Context<AnnounceFeeChange>,
     new_fee_bps: u16,
 ) -> Result<()> {
-    // Validate new fee
-    require!(
-        new_fee_bps >= MIN_TRANSFER_FEE_BPS && new_fee_bps <= MAX_TRANSFER_FEE_BPS,
-        ParadoxError::InvalidTransferFee
-    );
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
     
     let config = &mut ctx.accounts.token_config;
     let clock = Clock::get()?;
     
-    // Check if there's already a pending change
-    require!(
-        config.pending_fee_activate_time == 0 || clock.unix_timestamp >= config.pending_fee_cancel_time,
-        ParadoxError::FeeChangeTimelockNotExpired
-    );
-    
-    // Set pending fee change
-    config.pending_fee_bps = new_fee_bps;
-    config.pending_fee_activate_time = clock.unix_timestamp
-        .checked_add(FEE_CHANGE_TIMELOCK_SECONDS)
-        .ok_or(ParadoxError::MathOverflow)?;
-    config.pending_fee_cancel_time = config.pending_fee_activate_time
-        .checked_add(FEE_CHANGE_TIMELOCK_SECONDS)
-        .ok_or(ParadoxError::MathOverflow)?;
+    config.announce_fee_change(new_fee_bps, clock.unix_timestamp)?;
     
     emit!(FeeChangeAnnounced {
         mint: config.mint,
@@ -99,35 +82,12 @@ pub struct ExecuteFeeChange<'info> {
 }
 
 pub fn execute_fee_change_handler(ctx: Context<ExecuteFeeChange>) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
     let config = &mut ctx.accounts.token_config;
     let clock = Clock::get()?;
     
-    // Check if there's a pending change
-    require!(config.pending_fee_bps > 0, ParadoxError::NoPendingFeeChange);
-    
-    // Check if timelock has expired
-    require!(
-        clock.unix_timestamp >= config.pending_fee_activate_time,
-        ParadoxError::FeeChangeTimelockNotExpired
-    );
-    
-    // Check if cancel window has passed (can't execute after cancel window)
-    require!(
-        clock.unix_timestamp < config.pending_fee_cancel_time,
-        ParadoxError::FeeChangeTimelockNotExpired
-    );
-    
-    let old_fee = config.transfer_fee_bps;
-    let new_fee = config.pending_fee_bps;
-    
-    // Execute the fee change
-    config.transfer_fee_bps = new_fee;
-    config.last_fee_update = clock.unix_timestamp;
-    
-    // Clear pending
-    config.pending_fee_bps = 0;
-    config.pending_fee_activate_time = 0;
-    config.pending_fee_cancel_time = 0;
+    let (old_fee, new_fee) = config.execute_fee_change(clock.unix_timestamp)?;
     
     emit!(TransferFeeUpdated {
         mint: config.mint,
@@ -159,25 +119,13 @@ pub struct CancelFeeChange<'info> {
 }
 
 pub fn cancel_fee_change_handler(ctx: Context<CancelFeeChange>) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
     let config = &mut ctx.accounts.token_config;
     let clock = Clock::get()?;
     
-    // Check if there's a pending change
-    require!(config.pending_fee_bps > 0, ParadoxError::NoPendingFeeChange);
-    
     // Can cancel before activate_time or after cancel_time
-    require!(
-        clock.unix_timestamp < config.pending_fee_activate_time || 
-        clock.unix_timestamp >= config.pending_fee_cancel_time,
-        ParadoxError::FeeChangeTimelockNotExpired
-    );
-    
-    let cancelled_fee = config.pending_fee_bps;
-    
-    // Clear pending
-    config.pending_fee_bps = 0;
-    config.pending_fee_activate_time = 0;
-    config.pending_fee_cancel_time = 0;
+    let cancelled_fee = config.cancel_fee_change(clock.unix_timestamp)?;
     
     emit!(FeeChangeCancelled {
         mint: config.mint,
@@ -188,3 +136,186 @@ pub fn cancel_fee_change_handler(ctx: Context<CancelFeeChange>) -> Result<()> {
     
     Ok(())
 }
+
+// =============================================================================
+// UPDATE FEE SHARES (governance only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct UpdateFeeShares<'info> {
+    #[account(
+        constraint = governance.key() == token_config.governance @ ParadoxError::Unauthorized
+    )]
+    pub governance: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+}
+
+pub fn update_fee_shares_handler(
+    ctx: Context<UpdateFeeShares>,
+    lp_share_bps: u16,
+    burn_share_bps: u16,
+    treasury_share_bps: u16,
+) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
+    let config = &mut ctx.accounts.token_config;
+    
+    let old_lp_share_bps = config.lp_share_bps;
+    let old_burn_share_bps = config.burn_share_bps;
+    let old_treasury_share_bps = config.treasury_share_bps;
+    
+    config.lp_share_bps = lp_share_bps;
+    config.burn_share_bps = burn_share_bps;
+    config.treasury_share_bps = treasury_share_bps;
+    
+    // Invariant: shares must always sum to 100%
+    require!(config.validate_shares(), ParadoxError::InvalidFeeShares);
+    
+    emit!(FeeSharesUpdated {
+        mint: config.mint,
+        old_lp_share_bps,
+        old_burn_share_bps,
+        old_treasury_share_bps,
+        new_lp_share_bps: lp_share_bps,
+        new_burn_share_bps: burn_share_bps,
+        new_treasury_share_bps: treasury_share_bps,
+    });
+    
+    msg!("Fee shares updated: LP={} Burn={} Treasury={} (bps)", 
+         lp_share_bps, burn_share_bps, treasury_share_bps);
+    
+    Ok(())
+}
+
+// =============================================================================
+// SET HARVESTER REWARD (governance only)
+// =============================================================================
+
+pub fn set_harvester_reward_handler(
+    ctx: Context<UpdateFeeShares>,
+    harvester_reward_bps: u16,
+) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    require!(
+        harvester_reward_bps <= MAX_HARVESTER_REWARD_BPS,
+        ParadoxError::InvalidHarvesterReward
+    );
+    
+    let config = &mut ctx.accounts.token_config;
+    let old_reward_bps = config.harvester_reward_bps;
+    config.harvester_reward_bps = harvester_reward_bps;
+    
+    emit!(HarvesterRewardUpdated {
+        mint: config.mint,
+        old_reward_bps,
+        new_reward_bps: harvester_reward_bps,
+    });
+    
+    msg!("Harvester reward updated: {} → {} bps", old_reward_bps, harvester_reward_bps);
+    
+    Ok(())
+}
+
+// =============================================================================
+// SET FEE DUST THRESHOLD (admin only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetFeeDustThreshold<'info> {
+    #[account(
+        constraint = admin.key() == token_config.admin @ ParadoxError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+}
+
+pub fn set_fee_dust_threshold_handler(
+    ctx: Context<SetFeeDustThreshold>,
+    threshold: u64,
+) -> Result<()> {
+    require!(ctx.accounts.token_config.is_initialized(), ParadoxError::NotInitialized);
+    
+    let config = &mut ctx.accounts.token_config;
+    config.fee_dust_threshold = threshold;
+    
+    msg!("Fee dust threshold set to {} (effective: {})", threshold, config.effective_dust_threshold());
+    
+    Ok(())
+}
+
+// =============================================================================
+// GET TOKEN CONFIG STATUS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct GetTokenConfigStatus<'info> {
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, token_config.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
+}
+
+pub fn get_token_config_status_handler(ctx: Context<GetTokenConfigStatus>) -> Result<()> {
+    let config = &ctx.accounts.token_config;
+    
+    msg!("╔══════════════════════════════════════════════════════════════╗");
+    msg!("║           TOKEN CONFIG STATUS                                ║");
+    msg!("╠══════════════════════════════════════════════════════════════╣");
+    msg!("║ Mint: {}", config.mint);
+    msg!("║ Transfer Fee: {} bps", config.transfer_fee_bps);
+    msg!("║ Fee Bounds: {}-{} bps", config.fee_bounds().0, config.fee_bounds().1);
+    msg!("║ Shares: LP={} Burn={} Treasury={} (bps)",
+         config.lp_share_bps, config.burn_share_bps, config.treasury_share_bps);
+    msg!("║ Armageddon Level: {}", config.armageddon_level);
+    msg!("║");
+    msg!("║ Total Fees Collected: {}", config.total_fees_collected);
+    msg!("║ Total Fees Distributed: {}", config.total_fees_distributed);
+    msg!("║ Total Burned: {}", config.total_burned);
+    msg!("║ Fee Dust Threshold: {}", config.effective_dust_threshold());
+    msg!("║ Harvester Reward: {} bps", config.harvester_reward_bps);
+    msg!("╚══════════════════════════════════════════════════════════════╝");
+    
+    Ok(())
+}
+
+// =============================================================================
+// GET CONFIG SUMMARY (return data)
+// =============================================================================
+
+/// Lightweight read for clients looping over many mints - no logging
+pub fn get_config_summary_handler(ctx: Context<GetTokenConfigStatus>) -> Result<ConfigSummary> {
+    Ok(ctx.accounts.token_config.summary())
+}
+
+// =============================================================================
+// GET FEE CHANGE STATUS (return data)
+// =============================================================================
+
+pub fn get_fee_change_status_handler(ctx: Context<GetTokenConfigStatus>) -> Result<FeeChangeStatus> {
+    let status = ctx.accounts.token_config.fee_change_status(Clock::get()?.unix_timestamp);
+    
+    if status.has_pending {
+        msg!("Pending fee: {} bps | executable in {}s | window closes in {}s | executable now: {}",
+             status.pending_fee_bps,
+             status.seconds_until_executable,
+             status.seconds_until_window_closes,
+             status.is_executable);
+    } else {
+        msg!("No pending fee change");
+    }
+    
+    Ok(status)
+}
@@ -9,29 +9,43 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
     TokenInterface, TokenAccount, Mint, 
     TransferChecked, transfer_checked,
+    CloseAccount, close_account,
     InterfaceAccount, Interface,
 };
 
 use crate::{
-    state::DevVestingVault,
+    state::{DevVestingVault, ClaimPreview, TokenConfig},
     ParadoxError,
     DEV_VESTING_SEED,
-    MIN_TRANSFER_AMOUNT,
+    TOKEN_CONFIG_SEED,
     DevVestingInitialized,
     DevUnlockRequested,
     DevUnlockExecuted,
+    DevVestingClosed,
+    DevVestingTightened,
+    PayoutFeeModeUpdated,
     DEFAULT_COOLDOWN_SECONDS,
     DEFAULT_TIMELOCK_SECONDS,
     YEAR1_UNLOCK_RATE_BPS,
+    YEAR2_UNLOCK_RATE_BPS,
+    MIN_UNLOCK_RATE_BPS,
+    MAX_UNLOCK_RATE_BPS,
+    CURRENT_ACCOUNT_VERSION,
+    fee_mode::{FeeMode, payout_amounts, payout_amounts_at},
+    transfer_hook::transfer_checked_with_hook,
 };
 
-/// Token decimals (9 for PDOX - matches deployed mint)
-const TOKEN_DECIMALS: u8 = 9;
-
 // =============================================================================
 // INIT DEV VESTING
 // =============================================================================
 
+/// Amount to transfer so the vault receives exactly `locked_amount` after the
+/// transfer fee in effect at `epoch` (grossed up)
+pub fn vault_funding_amount(mint: &AccountInfo, locked_amount: u64, epoch: u64) -> Result<u64> {
+    let (gross, _) = payout_amounts_at(mint, locked_amount, FeeMode::GrossUp, epoch)?;
+    Ok(gross)
+}
+
 #[derive(Accounts)]
 pub struct InitDevVesting<'info> {
     #[account(mut)]
@@ -50,7 +64,12 @@ pub struct InitDevVesting<'info> {
     )]
     pub vault: Account<'info, DevVestingVault>,
     
-    #[account(mut)]
+    /// Receives the locked portion only (liquid_at_tge stays with the dev)
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == mint.key() @ ParadoxError::InvalidVault,
+        constraint = vault_token_account.owner == vault.key() @ ParadoxError::InvalidVault,
+    )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
     
     #[account(mut)]
@@ -66,7 +85,21 @@ pub fn init_dev_handler(
     liquid_at_tge: u64,
     cliff_seconds: i64,
     vesting_seconds: i64,
+    year1_rate_bps: Option<u16>,
+    year2_rate_bps: Option<u16>,
 ) -> Result<()> {
+    let year1_rate_bps = year1_rate_bps.unwrap_or(YEAR1_UNLOCK_RATE_BPS);
+    let year2_rate_bps = year2_rate_bps.unwrap_or(YEAR2_UNLOCK_RATE_BPS);
+    
+    // Validate unlock rates
+    require!(
+        (MIN_UNLOCK_RATE_BPS..=MAX_UNLOCK_RATE_BPS).contains(&year1_rate_bps)
+            && (MIN_UNLOCK_RATE_BPS..=MAX_UNLOCK_RATE_BPS).contains(&year2_rate_bps),
+        ParadoxError::InvalidUnlockRate
+    );
+    
+    DevVestingVault::validate_schedule(total_allocation, liquid_at_tge, cliff_seconds, vesting_seconds)?;
+    
     let vault = &mut ctx.accounts.vault;
     let clock = Clock::get()?;
     
@@ -89,11 +122,23 @@ pub fn init_dev_handler(
     vault.unlock_time = 0;
     vault.cooldown_seconds = DEFAULT_COOLDOWN_SECONDS;
     vault.timelock_seconds = DEFAULT_TIMELOCK_SECONDS;
-    vault.unlock_rate_bps = YEAR1_UNLOCK_RATE_BPS;
+    vault.unlock_rate_bps = year1_rate_bps;
     vault.total_unlocked = 0;
     vault.bump = ctx.bumps.vault;
+    vault.year1_rate_bps = year1_rate_bps;
+    vault.year2_rate_bps = year2_rate_bps;
+    vault.is_initialized = true;
+    vault.version = CURRENT_ACCOUNT_VERSION;
+    vault.fee_mode = FeeMode::Net as u8;
     
     // Transfer locked tokens to vault (uses transfer_checked for Token-2022)
+    let funding_gross = vault_funding_amount(
+        &ctx.accounts.mint.to_account_info(),
+        locked_amount,
+        clock.epoch,
+    )?;
+    let balance_before = ctx.accounts.vault_token_account.amount;
+    
     transfer_checked(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
@@ -104,10 +149,17 @@ pub fn init_dev_handler(
                 mint: ctx.accounts.mint.to_account_info(),
             },
         ),
-        locked_amount,
-        TOKEN_DECIMALS,
+        funding_gross,
+        ctx.accounts.mint.decimals,
     )?;
     
+    // The vault must hold exactly the locked portion - unlocks are sized from it
+    ctx.accounts.vault_token_account.reload()?;
+    require!(
+        ctx.accounts.vault_token_account.amount.checked_sub(balance_before) == Some(locked_amount),
+        ParadoxError::VestingFundingMismatch
+    );
+    
     emit!(DevVestingInitialized {
         dev: vault.dev,
         mint: vault.mint,
@@ -136,14 +188,29 @@ pub struct RequestDevUnlock<'info> {
         has_one = dev @ ParadoxError::Unauthorized,
     )]
     pub vault: Account<'info, DevVestingVault>,
+    
+    /// Per-mint dust floor
+    #[account(
+        seeds = [TOKEN_CONFIG_SEED, vault.mint.as_ref()],
+        bump = token_config.bump,
+    )]
+    pub token_config: Account<'info, TokenConfig>,
 }
 
 pub fn request_unlock_handler(ctx: Context<RequestDevUnlock>, amount: u64) -> Result<()> {
+    require!(ctx.accounts.vault.is_initialized(), ParadoxError::NotInitialized);
+    
     let vault = &mut ctx.accounts.vault;
     let clock = Clock::get()?;
     
     // SECURITY: Enforce minimum transfer amount (dust attack prevention)
-    require!(amount >= MIN_TRANSFER_AMOUNT, ParadoxError::AmountBelowMinimum);
+    require!(
+        amount >= ctx.accounts.token_config.effective_min_transfer_amount(),
+        ParadoxError::AmountBelowMinimum
+    );
+    
+    // No stacking: a new request would overwrite (and reset the timelock of) the pending one
+    require!(vault.pending_amount == 0, ParadoxError::UnlockAlreadyPending);
     
     // Check cliff
     require!(vault.cliff_passed(clock.unix_timestamp), ParadoxError::CliffNotPassed);
@@ -158,6 +225,15 @@ pub fn request_unlock_handler(ctx: Context<RequestDevUnlock>, amount: u64) -> Re
     let max_unlockable = vault.max_unlockable();
     require!(amount <= max_unlockable, ParadoxError::UnlockRateExceeded);
     
+    // Check amount doesn't exceed what has linearly vested so far
+    let requested_total = vault.total_unlocked
+        .checked_add(amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    require!(
+        requested_total <= vault.vested_amount(clock.unix_timestamp),
+        ParadoxError::UnlockRateExceeded
+    );
+    
     // Set pending unlock
     vault.pending_amount = amount;
     vault.last_request_time = clock.unix_timestamp;
@@ -169,6 +245,9 @@ pub fn request_unlock_handler(ctx: Context<RequestDevUnlock>, amount: u64) -> Re
         dev: vault.dev,
         amount,
         unlock_time: vault.unlock_time,
+        vested_total: vault.vested_amount(clock.unix_timestamp),
+        remaining_locked: vault.locked_amount,
+        percent_vested_bps: vault.percent_vested_bps(clock.unix_timestamp),
     });
     
     Ok(())
@@ -199,10 +278,22 @@ pub struct ExecuteDevUnlock<'info> {
     #[account(mut)]
     pub dev_token_account: InterfaceAccount<'info, TokenAccount>,
     
+    /// Optional: secondary destination (e.g. tax reserve) for split_bps of the unlock
+    #[account(
+        mut,
+        constraint = secondary_token_account.mint == mint.key() @ ParadoxError::InvalidVault,
+    )]
+    pub secondary_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+    
     pub token_program: Interface<'info, TokenInterface>,
 }
 
-pub fn execute_unlock_handler(ctx: Context<ExecuteDevUnlock>) -> Result<()> {
+pub fn execute_unlock_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteDevUnlock<'info>>,
+    split_bps: Option<u16>,
+) -> Result<()> {
+    require!(ctx.accounts.vault.is_initialized(), ParadoxError::NotInitialized);
+    
     let vault = &mut ctx.accounts.vault;
     let clock = Clock::get()?;
     
@@ -212,6 +303,68 @@ pub fn execute_unlock_handler(ctx: Context<ExecuteDevUnlock>) -> Result<()> {
     
     let amount = vault.pending_amount;
     
+    // Optional split: split_bps of the unlock goes to secondary_token_account
+    let split_bps = split_bps.unwrap_or(0);
+    require!(split_bps <= 10_000, ParadoxError::InvalidSplit);
+    
+    let split_amount = ((amount as u128)
+        .saturating_mul(split_bps as u128)
+        / 10_000) as u64;
+    let primary_amount = amount
+        .checked_sub(split_amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    require!(
+        primary_amount.checked_add(split_amount) == Some(amount),
+        ParadoxError::InvalidSplit
+    );
+    require!(
+        split_amount == 0 || ctx.accounts.secondary_token_account.is_some(),
+        ParadoxError::InvalidSplit
+    );
+    
+    // Gross vs net per leg (each leg pays its own transfer fee)
+    let fee_mode = FeeMode::from_byte(vault.fee_mode).ok_or(ParadoxError::InvalidFeeMode)?;
+    let mint_info = ctx.accounts.mint.to_account_info();
+    let (primary_gross, primary_net) = payout_amounts(&mint_info, primary_amount, fee_mode)?;
+    let (split_gross, split_net) = payout_amounts(&mint_info, split_amount, fee_mode)?;
+    let gross_amount = primary_gross
+        .checked_add(split_gross)
+        .ok_or(ParadoxError::MathOverflow)?;
+    let net_amount = primary_net
+        .checked_add(split_net)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    // Clear error instead of an opaque Token-2022 failure if the vault is underfunded
+    ctx.accounts.vault_token_account.reload()?;
+    require!(
+        ctx.accounts.vault_token_account.amount >= amount,
+        ParadoxError::InsufficientVaultBalance
+    );
+    
+    // Gross-up must come from surplus - tokens still locked stay fully backed
+    if gross_amount > amount {
+        let still_locked = vault.locked_amount.saturating_sub(amount);
+        let required = gross_amount
+            .checked_add(still_locked)
+            .ok_or(ParadoxError::MathOverflow)?;
+        require!(
+            ctx.accounts.vault_token_account.amount >= required,
+            ParadoxError::FeeGrossUpNotCovered
+        );
+    }
+    
+    // Effects before interactions: settle the unlock and persist it before any CPI,
+    // so a transfer hook never observes the pending amount as still claimable.
+    // A failed transfer reverts the whole transaction - nothing to roll back.
+    vault.locked_amount = vault.locked_amount
+        .checked_sub(amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    vault.pending_amount = 0;
+    vault.total_unlocked = vault.total_unlocked
+        .checked_add(amount)
+        .ok_or(ParadoxError::MathOverflow)?;
+    vault.exit(ctx.program_id)?;
+    
     // Transfer tokens (uses transfer_checked for Token-2022 fee compliance)
     let seeds: &[&[u8]] = &[
         DEV_VESTING_SEED,
@@ -220,35 +373,298 @@ pub fn execute_unlock_handler(ctx: Context<ExecuteDevUnlock>) -> Result<()> {
         &[vault.bump],
     ];
     
-    transfer_checked(
+    if primary_amount > 0 {
+        transfer_checked_with_hook(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.vault_token_account.to_account_info(),
+            &mint_info,
+            &ctx.accounts.dev_token_account.to_account_info(),
+            &vault.to_account_info(),
+            ctx.remaining_accounts,
+            primary_gross,
+            ctx.accounts.mint.decimals,
+            &[seeds],
+        )?;
+    }
+    
+    let mut secondary_destination = Pubkey::default();
+    if split_amount > 0 {
+        if let Some(secondary) = &ctx.accounts.secondary_token_account {
+            secondary_destination = secondary.key();
+            transfer_checked_with_hook(
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.vault_token_account.to_account_info(),
+                &mint_info,
+                &secondary.to_account_info(),
+                &vault.to_account_info(),
+                ctx.remaining_accounts,
+                split_gross,
+                ctx.accounts.mint.decimals,
+                &[seeds],
+            )?;
+        }
+    }
+    
+    emit!(DevUnlockExecuted {
+        dev: vault.dev,
+        amount,
+        remaining_locked: vault.locked_amount,
+        vested_total: vault.vested_amount(clock.unix_timestamp),
+        percent_vested_bps: vault.percent_vested_bps(clock.unix_timestamp),
+        split_amount,
+        secondary_destination,
+        gross_amount,
+        net_amount,
+    });
+    
+    Ok(())
+}
+
+// =============================================================================
+// SET VESTING FEE MODE
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct SetVestingFeeMode<'info> {
+    pub dev: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DEV_VESTING_SEED, dev.key().as_ref(), vault.mint.as_ref()],
+        bump = vault.bump,
+        has_one = dev @ ParadoxError::Unauthorized,
+    )]
+    pub vault: Account<'info, DevVestingVault>,
+}
+
+pub fn set_fee_mode_handler(ctx: Context<SetVestingFeeMode>, fee_mode: u8) -> Result<()> {
+    require!(ctx.accounts.vault.is_initialized(), ParadoxError::NotInitialized);
+    let mode = FeeMode::from_byte(fee_mode).ok_or(ParadoxError::InvalidFeeMode)?;
+    
+    let vault = &mut ctx.accounts.vault;
+    let old_mode = vault.fee_mode;
+    vault.fee_mode = fee_mode;
+    
+    emit!(PayoutFeeModeUpdated {
+        vault: vault.key(),
+        mint: vault.mint,
+        old_mode,
+        new_mode: fee_mode,
+    });
+    
+    msg!("Dev vesting fee mode: {}", mode.name());
+    
+    Ok(())
+}
+
+// =============================================================================
+// TIGHTEN VESTING SCHEDULE (extend only)
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct TightenVestingSchedule<'info> {
+    pub dev: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [DEV_VESTING_SEED, dev.key().as_ref(), vault.mint.as_ref()],
+        bump = vault.bump,
+        has_one = dev @ ParadoxError::Unauthorized,
+    )]
+    pub vault: Account<'info, DevVestingVault>,
+}
+
+pub fn tighten_schedule_handler(
+    ctx: Context<TightenVestingSchedule>,
+    cliff_seconds: Option<i64>,
+    cooldown_seconds: Option<i64>,
+    timelock_seconds: Option<i64>,
+) -> Result<()> {
+    require!(ctx.accounts.vault.is_initialized(), ParadoxError::NotInitialized);
+    
+    let vault = &mut ctx.accounts.vault;
+    
+    let cliff_seconds = cliff_seconds.unwrap_or(vault.cliff_seconds);
+    let cooldown_seconds = cooldown_seconds.unwrap_or(vault.cooldown_seconds);
+    let timelock_seconds = timelock_seconds.unwrap_or(vault.timelock_seconds);
+    
+    // SECURITY: Never weaken existing guarantees
+    require!(
+        cliff_seconds >= vault.cliff_seconds
+            && cooldown_seconds >= vault.cooldown_seconds
+            && timelock_seconds >= vault.timelock_seconds,
+        ParadoxError::VestingScheduleCannotShorten
+    );
+    
+    // Cliff must stay inside the vesting window
+    require!(cliff_seconds < vault.vesting_seconds, ParadoxError::InvalidVestingSchedule);
+    
+    vault.cliff_seconds = cliff_seconds;
+    vault.cooldown_seconds = cooldown_seconds;
+    vault.timelock_seconds = timelock_seconds;
+    
+    emit!(DevVestingTightened {
+        dev: vault.dev,
+        mint: vault.mint,
+        cliff_seconds,
+        cooldown_seconds,
+        timelock_seconds,
+    });
+    
+    msg!("Dev vesting tightened: cliff={}s cooldown={}s timelock={}s",
+         cliff_seconds, cooldown_seconds, timelock_seconds);
+    
+    Ok(())
+}
+
+// =============================================================================
+// GET DEV VESTING STATUS
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct GetVestingStatus<'info> {
+    #[account(
+        seeds = [DEV_VESTING_SEED, vault.dev.as_ref(), vault.mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, DevVestingVault>,
+}
+
+pub fn get_vesting_status_handler(ctx: Context<GetVestingStatus>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let clock = Clock::get()?;
+    
+    msg!("╔══════════════════════════════════════════════════════════════╗");
+    msg!("║           DEV VESTING STATUS                                 ║");
+    msg!("╠══════════════════════════════════════════════════════════════╣");
+    msg!("║ Dev: {}", vault.dev);
+    msg!("║ Total Allocation: {}", vault.total_allocation);
+    msg!("║ Liquid at TGE: {}", vault.liquid_at_tge);
+    msg!("║ Vested (locked portion): {}", vault.vested_amount(clock.unix_timestamp));
+    msg!("║ Total Unlocked: {}", vault.total_unlocked);
+    msg!("║ Total Claimable (incl. TGE): {}", vault.total_claimable(clock.unix_timestamp));
+    msg!("║ Claimable from vault: {}", vault.claimable_from_vault(clock.unix_timestamp));
+    msg!("║ Locked: {}", vault.locked_amount);
+    msg!("║ Pending: {}", vault.pending_amount);
+    msg!("║ Cliff passed: {}", vault.cliff_passed(clock.unix_timestamp));
+    msg!("╚══════════════════════════════════════════════════════════════╝");
+    
+    Ok(())
+}
+
+// =============================================================================
+// GET CLAIM PREVIEW
+// =============================================================================
+
+pub fn get_claim_preview_handler(ctx: Context<GetVestingStatus>) -> Result<ClaimPreview> {
+    let preview = ctx.accounts.vault.claim_preview(Clock::get()?.unix_timestamp);
+    
+    msg!("Pending: {} (unlocks at {}) | cooldown passes in {}s | requestable now: {}",
+         preview.pending_amount,
+         preview.unlock_time,
+         preview.seconds_until_cooldown_passes,
+         preview.max_additional_requestable_now);
+    
+    Ok(preview)
+}
+
+// =============================================================================
+// CLOSE VESTING VAULT
+// =============================================================================
+
+#[derive(Accounts)]
+pub struct CloseVestingVault<'info> {
+    #[account(mut)]
+    pub dev: Signer<'info>,
+    
+    #[account(
+        mut,
+        close = dev,
+        seeds = [DEV_VESTING_SEED, dev.key().as_ref(), vault.mint.as_ref()],
+        bump = vault.bump,
+        has_one = dev @ ParadoxError::Unauthorized,
+    )]
+    pub vault: Account<'info, DevVestingVault>,
+    
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == vault.token_account @ ParadoxError::InvalidVault,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn close_vesting_vault_handler(ctx: Context<CloseVestingVault>) -> Result<()> {
+    require!(ctx.accounts.vault.is_initialized(), ParadoxError::NotInitialized);
+    
+    let vault = &ctx.accounts.vault;
+    
+    // Prevent premature closing
+    require!(vault.is_complete(), ParadoxError::VestingNotComplete);
+    require!(ctx.accounts.vault_token_account.amount == 0, ParadoxError::VaultNotEmpty);
+    
+    // Close token account, returning its rent to the dev
+    let seeds: &[&[u8]] = &[
+        DEV_VESTING_SEED,
+        vault.dev.as_ref(),
+        vault.mint.as_ref(),
+        &[vault.bump],
+    ];
+    
+    close_account(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault_token_account.to_account_info(),
-                to: ctx.accounts.dev_token_account.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.vault_token_account.to_account_info(),
+                destination: ctx.accounts.dev.to_account_info(),
                 authority: vault.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
             },
             &[seeds],
         ),
-        amount,
-        TOKEN_DECIMALS,
     )?;
     
-    // Update state (checked arithmetic)
-    vault.locked_amount = vault.locked_amount
-        .checked_sub(amount)
-        .ok_or(ParadoxError::MathOverflow)?;
-    vault.pending_amount = 0;
-    vault.total_unlocked = vault.total_unlocked
-        .checked_add(amount)
-        .ok_or(ParadoxError::MathOverflow)?;
-    
-    emit!(DevUnlockExecuted {
+    emit!(DevVestingClosed {
         dev: vault.dev,
-        amount,
-        remaining_locked: vault.locked_amount,
+        mint: vault.mint,
+        total_unlocked: vault.total_unlocked,
     });
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee_mode::tests::mint_with_transfer_fee;
+    
+    /// What a transfer_checked of `sent` leaves in the destination
+    fn received(mint: &AccountInfo, sent: u64) -> u64 {
+        payout_amounts_at(mint, sent, FeeMode::Net, 0).unwrap().1
+    }
+    
+    #[test]
+    fn vault_funding_lands_exactly_locked_amount() {
+        let locked_amount = 1_000_003;
+        
+        // 1% uncapped, and a fee that hits its cap
+        for (fee_bps, maximum_fee) in [(100, u64::MAX), (300, 50)] {
+            let mut data = mint_with_transfer_fee(fee_bps, maximum_fee);
+            let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+            let mut lamports = 0;
+            let mint = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+            
+            // GrossUp funding: the vault ends up holding exactly locked_amount
+            let funding = vault_funding_amount(&mint, locked_amount, 0).unwrap();
+            assert_eq!(funding, payout_amounts_at(&mint, locked_amount, FeeMode::GrossUp, 0).unwrap().0);
+            assert!(funding > locked_amount);
+            assert_eq!(received(&mint, funding), locked_amount);
+            
+            // Net funding (sending locked_amount as-is) would leave the vault short
+            let (net_sent, net_received) = payout_amounts_at(&mint, locked_amount, FeeMode::Net, 0).unwrap();
+            assert_eq!(net_sent, locked_amount);
+            assert!(net_received < locked_amount);
+            assert_eq!(received(&mint, net_sent), net_received);
+        }
+    }
+}
@@ -11,8 +11,23 @@
 
 use anchor_lang::prelude::*;
 
+/// Log remaining compute units with a label (`debug-compute` feature only)
+/// Compiles to nothing when the feature is off
+macro_rules! log_compute {
+    ($label:expr) => {
+        #[cfg(feature = "debug-compute")]
+        {
+            anchor_lang::prelude::msg!("CU [{}]", $label);
+            anchor_lang::solana_program::log::sol_log_compute_units();
+        }
+    };
+}
+
 pub mod state;
 pub mod instructions;
+pub mod lp_value;
+pub mod fee_mode;
+pub mod transfer_hook;
 
 use state::*;
 use instructions::*;
@@ -29,6 +44,9 @@ pub const LP_LOCK_SEED: &[u8] = b"lp_lock";
 pub const DEV_VESTING_SEED: &[u8] = b"dev_vesting";
 pub const DAO_TREASURY_SEED: &[u8] = b"dao_treasury";
 pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+pub const PROGRAM_STATS_SEED: &[u8] = b"program_stats";
+pub const TREASURY_ALLOWLIST_SEED: &[u8] = b"treasury_allowlist";
+pub const LP_HOLDER_GUARD_SEED: &[u8] = b"lp_holder_guard";
 
 /// Emergency window for LP lock: 15 minutes
 pub const LP_EMERGENCY_WINDOW_SECONDS: i64 = 15 * 60;
@@ -46,9 +64,16 @@ pub const DEFAULT_TRANSFER_FEE_BPS: u16 = 300;
 /// Minimum transfer fee: 1% (100 bps)
 pub const MIN_TRANSFER_FEE_BPS: u16 = 100;
 
+/// Maximum keeper reward for harvest_and_distribute: 1% of harvested amount
+pub const MAX_HARVESTER_REWARD_BPS: u16 = 100;
+
 /// Maximum transfer fee: 3% (300 bps)  
 pub const MAX_TRANSFER_FEE_BPS: u16 = 300;
 
+/// Hard protocol cap for per-config fee bounds: 10% (1000 bps)
+/// MIN/MAX_TRANSFER_FEE_BPS are the default per-config bounds within this
+pub const PROTOCOL_MAX_FEE_BPS: u16 = 1000;
+
 /// Minimum transfer amount to prevent dust attack (fee must be >= 1 raw unit)
 /// At 300 bps (3%), amounts below 34 result in 0 fee
 pub const MIN_TRANSFER_AMOUNT: u64 = 34;
@@ -56,6 +81,15 @@ pub const MIN_TRANSFER_AMOUNT: u64 = 34;
 /// Fee change timelock: 24 hours (prevents front-running)
 pub const FEE_CHANGE_TIMELOCK_SECONDS: i64 = 24 * 60 * 60;
 
+/// Window to execute an announced fee change after the timelock: 24 hours by default
+pub const DEFAULT_FEE_EXECUTION_WINDOW_HOURS: u16 = 24;
+
+/// Shortest fee execution window a config may set: 1 hour
+pub const MIN_FEE_EXECUTION_WINDOW_HOURS: u16 = 1;
+
+/// Minimum DAO treasury withdrawal timelock: 24 hours
+pub const MIN_TREASURY_TIMELOCK_SECONDS: i64 = 24 * 60 * 60;
+
 /// Default LP share: 70%
 pub const DEFAULT_LP_SHARE_BPS: u16 = 7000;
 
@@ -83,6 +117,15 @@ pub const YEAR1_UNLOCK_RATE_BPS: u16 = 500;
 /// Year 2+ unlock rate: 10% per request
 pub const YEAR2_UNLOCK_RATE_BPS: u16 = 1000;
 
+/// Minimum configurable unlock rate: 1% per request
+pub const MIN_UNLOCK_RATE_BPS: u16 = 100;
+
+/// Maximum configurable unlock rate: 20% per request
+pub const MAX_UNLOCK_RATE_BPS: u16 = 2000;
+
+/// Current state account layout version
+pub const CURRENT_ACCOUNT_VERSION: u8 = 1;
+
 // =============================================================================
 // PROGRAM
 // =============================================================================
@@ -97,12 +140,17 @@ pub mod paradox_token {
 
     /// Initialize token configuration
     /// Called once after token mint is created
+    /// governance: separate key (multisig) for DEFCON 1 and other governance-gated calls
     pub fn init_token_config(
         ctx: Context<InitTokenConfig>,
         transfer_fee_bps: u16,
         lp_share_bps: u16,
         burn_share_bps: u16,
         treasury_share_bps: u16,
+        min_fee_bps: Option<u16>,
+        max_fee_bps: Option<u16>,
+        fee_execution_window_hours: Option<u16>,
+        governance: Pubkey,
     ) -> Result<()> {
         instructions::init_token_config::handler(
             ctx,
@@ -110,6 +158,10 @@ pub mod paradox_token {
             lp_share_bps,
             burn_share_bps,
             treasury_share_bps,
+            min_fee_bps,
+            max_fee_bps,
+            fee_execution_window_hours,
+            governance,
         )
     }
 
@@ -135,26 +187,99 @@ pub mod paradox_token {
         instructions::update_token_config::cancel_fee_change_handler(ctx)
     }
 
+    /// Update fee distribution shares (governance only)
+    /// Shares must sum to 10000 bps
+    pub fn update_fee_shares(
+        ctx: Context<UpdateFeeShares>,
+        lp_share_bps: u16,
+        burn_share_bps: u16,
+        treasury_share_bps: u16,
+    ) -> Result<()> {
+        instructions::update_token_config::update_fee_shares_handler(
+            ctx,
+            lp_share_bps,
+            burn_share_bps,
+            treasury_share_bps,
+        )
+    }
+
+    /// Set keeper reward paid to harvest_and_distribute callers (governance only)
+    /// Capped at MAX_HARVESTER_REWARD_BPS, 0 disables
+    pub fn set_harvester_reward(ctx: Context<UpdateFeeShares>, harvester_reward_bps: u16) -> Result<()> {
+        instructions::update_token_config::set_harvester_reward_handler(ctx, harvester_reward_bps)
+    }
+
+    /// Set fee vault dust threshold for sweep_fee_dust (admin only, 0 = default)
+    pub fn set_fee_dust_threshold(ctx: Context<SetFeeDustThreshold>, threshold: u64) -> Result<()> {
+        instructions::update_token_config::set_fee_dust_threshold_handler(ctx, threshold)
+    }
+
+    /// Get token config status (fees, shares, lifetime burn)
+    pub fn get_token_config_status(ctx: Context<GetTokenConfigStatus>) -> Result<()> {
+        instructions::update_token_config::get_token_config_status_handler(ctx)
+    }
+
+    /// Get pending fee change timing via return data
+    pub fn get_fee_change_status(ctx: Context<GetTokenConfigStatus>) -> Result<FeeChangeStatus> {
+        instructions::update_token_config::get_fee_change_status_handler(ctx)
+    }
+
+    /// Get compact token config summary via return data (for multi-mint dashboards)
+    pub fn get_config_summary(ctx: Context<GetTokenConfigStatus>) -> Result<ConfigSummary> {
+        instructions::update_token_config::get_config_summary_handler(ctx)
+    }
+
     // =========================================================================
     // LP GROWTH MANAGER
     // =========================================================================
 
     /// Initialize LP Growth Manager
     /// Creates the PDA that controls automatic LP growth from fees
+    /// max_mint_ratio_bps: price-drop circuit breaker (default 20%, 0 = disabled)
+    /// cooldown_seconds: at least 1 (one growth per timestamp)
     pub fn init_lp_growth(
         ctx: Context<InitLpGrowth>,
         min_fee_threshold: u64,
         cooldown_seconds: i64,
+        max_mint_ratio_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::lp_growth::init_handler(ctx, min_fee_threshold, cooldown_seconds, max_mint_ratio_bps)
+    }
+
+    /// Transfer mint authority to the LP growth PDA (signed by current mint authority)
+    /// One-time setup required before execute_lp_growth can mint
+    pub fn assign_mint_authority_to_growth(ctx: Context<AssignMintAuthorityToGrowth>) -> Result<()> {
+        instructions::lp_growth::assign_mint_authority_handler(ctx)
+    }
+
+    /// Swap LP share fee tokens to SOL (unwraps WSOL into the manager PDA)
+    /// Must run before execute_lp_growth - growth deposits SOL, fees arrive as tokens
+    pub fn swap_fees_to_sol(
+        ctx: Context<SwapFeesToSol>,
+        token_amount: u64,
+        min_sol_out: u64,
     ) -> Result<()> {
-        instructions::lp_growth::init_handler(ctx, min_fee_threshold, cooldown_seconds)
+        instructions::lp_growth::swap_fees_to_sol_handler(ctx, token_amount, min_sol_out)
     }
 
     /// Execute LP Growth
-    /// Uses accumulated fees to add liquidity to the pool
+    /// Uses accumulated SOL (from swapped fees) to add liquidity to the pool
     pub fn execute_lp_growth(ctx: Context<ExecuteLpGrowth>) -> Result<()> {
         instructions::lp_growth::execute_handler(ctx)
     }
 
+    /// Quote the next LP growth without executing (read-only)
+    /// Returns sol_to_add, tokens_to_mint and estimated LP out via return data
+    pub fn quote_lp_growth(ctx: Context<QuoteLpGrowth>) -> Result<LpGrowthQuote> {
+        instructions::lp_growth::quote_handler(ctx)
+    }
+
+    /// Execute LP Growth into a Meteora DLMM position owned by the growth PDA
+    /// Adds liquidity across the active bin (builds without `meteora` return MeteoraNotEnabled)
+    pub fn execute_lp_growth_meteora(ctx: Context<ExecuteLpGrowthMeteora>) -> Result<()> {
+        instructions::lp_growth_meteora::execute_meteora_handler(ctx)
+    }
+
     /// Lock LP Growth (emergency)
     pub fn lock_lp_growth(ctx: Context<LockLpGrowth>) -> Result<()> {
         instructions::lp_growth::lock_handler(ctx)
@@ -165,18 +290,33 @@ pub mod paradox_token {
         instructions::lp_growth::unlock_handler(ctx)
     }
 
+    /// Split growth SOL between lp_pool and a second pool (admin only)
+    /// Weights in bps must sum to 10000; Pubkey::default() + (10000, 0) = single pool
+    pub fn set_lp_growth_pools(
+        ctx: Context<SetLpGrowthPools>,
+        secondary_lp_pool: Pubkey,
+        primary_weight_bps: u16,
+        secondary_weight_bps: u16,
+    ) -> Result<()> {
+        instructions::lp_growth::set_pools_handler(ctx, secondary_lp_pool, primary_weight_bps, secondary_weight_bps)
+    }
+
     // =========================================================================
     // DEV VESTING
     // =========================================================================
 
     /// Initialize dev vesting vault
     /// Locks dev tokens with cliff + linear vesting
+    /// Only total_allocation - liquid_at_tge moves in; the liquid part stays with the dev
+    /// Unlock rates default to 5% (year 1) / 10% (year 2+) when not provided
     pub fn init_dev_vesting(
         ctx: Context<InitDevVesting>,
         total_allocation: u64,
         liquid_at_tge: u64,
         cliff_seconds: i64,
         vesting_seconds: i64,
+        year1_rate_bps: Option<u16>,
+        year2_rate_bps: Option<u16>,
     ) -> Result<()> {
         instructions::vesting::init_dev_handler(
             ctx,
@@ -184,6 +324,8 @@ pub mod paradox_token {
             liquid_at_tge,
             cliff_seconds,
             vesting_seconds,
+            year1_rate_bps,
+            year2_rate_bps,
         )
     }
 
@@ -198,8 +340,55 @@ pub mod paradox_token {
 
     /// Execute dev unlock
     /// Withdraws after timelock expires
-    pub fn execute_dev_unlock(ctx: Context<ExecuteDevUnlock>) -> Result<()> {
-        instructions::vesting::execute_unlock_handler(ctx)
+    /// Optional split_bps routes that share to secondary_token_account (e.g. tax reserve)
+    /// Hook-enabled mints: pass the transfer hook's extra accounts as remaining accounts
+    pub fn execute_dev_unlock<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteDevUnlock<'info>>,
+        split_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::vesting::execute_unlock_handler(ctx, split_bps)
+    }
+
+    /// Set dev vesting payout fee mode (dev only)
+    /// 0 = dev bears the transfer fee, 1 = vault surplus grosses up so dev gets exactly `amount`
+    pub fn set_vesting_fee_mode(
+        ctx: Context<SetVestingFeeMode>,
+        fee_mode: u8,
+    ) -> Result<()> {
+        instructions::vesting::set_fee_mode_handler(ctx, fee_mode)
+    }
+
+    /// Tighten dev vesting schedule (dev only, values can only increase)
+    /// None leaves a value unchanged
+    pub fn tighten_vesting_schedule(
+        ctx: Context<TightenVestingSchedule>,
+        cliff_seconds: Option<i64>,
+        cooldown_seconds: Option<i64>,
+        timelock_seconds: Option<i64>,
+    ) -> Result<()> {
+        instructions::vesting::tighten_schedule_handler(
+            ctx,
+            cliff_seconds,
+            cooldown_seconds,
+            timelock_seconds,
+        )
+    }
+
+    /// Get dev vesting status (including TGE-liquid claimable total)
+    pub fn get_dev_vesting_status(ctx: Context<GetVestingStatus>) -> Result<()> {
+        instructions::vesting::get_vesting_status_handler(ctx)
+    }
+
+    /// Preview pending vs. additionally requestable amounts via return data
+    /// Requests can't be stacked: requestable is 0 while one is pending
+    pub fn get_claim_preview(ctx: Context<GetVestingStatus>) -> Result<ClaimPreview> {
+        instructions::vesting::get_claim_preview_handler(ctx)
+    }
+
+    /// Close completed dev vesting vault
+    /// Closes the vault token account and PDA, returning rent to dev
+    pub fn close_vesting_vault(ctx: Context<CloseVestingVault>) -> Result<()> {
+        instructions::vesting::close_vesting_vault_handler(ctx)
     }
 
     // =========================================================================
@@ -212,16 +401,55 @@ pub mod paradox_token {
         governance: Pubkey,
         max_spend_bps_per_period: u16,
         period_seconds: i64,
+        timelock_seconds: i64,
     ) -> Result<()> {
         instructions::treasury::init_handler(
             ctx,
             governance,
             max_spend_bps_per_period,
             period_seconds,
+            timelock_seconds,
         )
     }
 
+    /// Set DAO treasury payout fee mode (governance only)
+    /// 0 = recipient bears the transfer fee, 1 = treasury grosses up so recipient gets exactly `amount`
+    pub fn set_treasury_fee_mode(
+        ctx: Context<SetTreasuryFeeMode>,
+        fee_mode: u8,
+    ) -> Result<()> {
+        instructions::treasury::set_fee_mode_handler(ctx, fee_mode)
+    }
+
+    /// Update DAO treasury timelock (governance only, can only be lengthened)
+    pub fn update_treasury_timelock(
+        ctx: Context<UpdateTreasuryTimelock>,
+        new_timelock_seconds: i64,
+    ) -> Result<()> {
+        instructions::treasury::update_timelock_handler(ctx, new_timelock_seconds)
+    }
+
+    /// Propose new DAO treasury governance (current governance only)
+    /// Pass Pubkey::default() to cancel a pending transfer
+    pub fn transfer_treasury_governance(
+        ctx: Context<TransferTreasuryGovernance>,
+        new_governance: Pubkey,
+    ) -> Result<()> {
+        instructions::treasury::transfer_governance_handler(ctx, new_governance)
+    }
+
+    /// Accept DAO treasury governance (signed by the pending governance)
+    pub fn accept_treasury_governance(ctx: Context<AcceptTreasuryGovernance>) -> Result<()> {
+        instructions::treasury::accept_governance_handler(ctx)
+    }
+
+    /// Deposit tokens into the DAO treasury (keeps `balance` in sync)
+    pub fn deposit_to_treasury(ctx: Context<DepositToTreasury>, amount: u64) -> Result<()> {
+        instructions::treasury::deposit_handler(ctx, amount)
+    }
+
     /// Propose DAO withdrawal
+    /// Rejected while another proposal is pending; each proposal gets a new proposal_nonce
     pub fn propose_dao_withdrawal(
         ctx: Context<ProposeDaoWithdrawal>,
         amount: u64,
@@ -231,9 +459,89 @@ pub mod paradox_token {
         instructions::treasury::propose_handler(ctx, amount, recipient, reason)
     }
 
+    /// Configure approver multisig for large DAO withdrawals (governance only)
+    /// threshold = 0 disables; above it, `required_approvals` approvers must sign
+    pub fn configure_treasury_approvals(
+        ctx: Context<ConfigureTreasuryApprovals>,
+        large_withdrawal_threshold: u64,
+        required_approvals: u8,
+        approvers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::treasury::configure_approvals_handler(
+            ctx,
+            large_withdrawal_threshold,
+            required_approvals,
+            approvers,
+        )
+    }
+
+    /// Enable the treasury recipient allowlist (governance only, one-way)
+    /// After this, proposals must target a recipient added by `allowlist_admin`
+    pub fn init_treasury_allowlist(
+        ctx: Context<InitTreasuryAllowlist>,
+        allowlist_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::treasury::init_allowlist_handler(ctx, allowlist_admin)
+    }
+
+    /// Approve a treasury recipient (allowlist admin only)
+    pub fn add_treasury_recipient(
+        ctx: Context<UpdateTreasuryAllowlist>,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::treasury::add_recipient_handler(ctx, recipient)
+    }
+
+    /// Remove a treasury recipient (allowlist admin only)
+    pub fn remove_treasury_recipient(
+        ctx: Context<UpdateTreasuryAllowlist>,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::treasury::remove_recipient_handler(ctx, recipient)
+    }
+
+    /// Approve the pending DAO withdrawal (approver only)
+    pub fn approve_dao_withdrawal(ctx: Context<ApproveDaoWithdrawal>) -> Result<()> {
+        instructions::treasury::approve_handler(ctx)
+    }
+
     /// Execute DAO withdrawal (after timelock)
-    pub fn execute_dao_withdrawal(ctx: Context<ExecuteDaoWithdrawal>) -> Result<()> {
-        instructions::treasury::execute_handler(ctx)
+    /// Creates the recipient's ATA first if it doesn't exist (executor pays rent)
+    /// `proposal_nonce` must match the pending proposal (from DaoWithdrawalProposed)
+    /// Hook-enabled mints: pass the transfer hook's extra accounts as remaining accounts
+    pub fn execute_dao_withdrawal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteDaoWithdrawal<'info>>,
+        proposal_nonce: u64,
+    ) -> Result<()> {
+        instructions::treasury::execute_handler(ctx, proposal_nonce)
+    }
+
+    /// Cancel the pending DAO withdrawal (governance only)
+    pub fn cancel_dao_withdrawal(
+        ctx: Context<CancelDaoWithdrawal>,
+        proposal_nonce: u64,
+    ) -> Result<()> {
+        instructions::treasury::cancel_handler(ctx, proposal_nonce)
+    }
+
+    /// Get DAO treasury status (spendable now, next reset, pending proposal)
+    pub fn get_treasury_status(ctx: Context<GetTreasuryStatus>) -> Result<TreasuryStatus> {
+        instructions::treasury::get_status_handler(ctx)
+    }
+
+    // =========================================================================
+    // PROGRAM STATS (optional, per deployer)
+    // =========================================================================
+
+    /// Create the deployer-wide stats account
+    /// Pass it to init_token_config / harvests / execute_lp_growth to aggregate
+    pub fn init_program_stats(ctx: Context<InitProgramStats>) -> Result<()> {
+        instructions::program_stats::init_program_stats_handler(ctx)
+    }
+
+    /// Get deployer-wide stats via return data
+    pub fn get_program_stats(ctx: Context<GetProgramStats>) -> Result<ProgramStatsSummary> {
+        instructions::program_stats::get_program_stats_handler(ctx)
     }
 
     // =========================================================================
@@ -241,22 +549,91 @@ pub mod paradox_token {
     // =========================================================================
 
     /// Initialize Armageddon state account
-    pub fn init_armageddon(ctx: Context<InitArmageddon>) -> Result<()> {
-        instructions::armageddon::init_armageddon_handler(ctx)
+    /// Drop thresholds default to 50/75/90% when not provided
+    pub fn init_armageddon(
+        ctx: Context<InitArmageddon>,
+        threshold_l1_bps: Option<u16>,
+        threshold_l2_bps: Option<u16>,
+        threshold_l3_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::armageddon::init_armageddon_handler(
+            ctx,
+            threshold_l1_bps,
+            threshold_l2_bps,
+            threshold_l3_bps,
+        )
+    }
+
+    /// Set baseline (healthy) LP value used as reference for DEFCON thresholds
+    pub fn set_baseline_lp_value(
+        ctx: Context<SetBaselineLpValue>,
+        baseline_lp_value: u64,
+    ) -> Result<()> {
+        instructions::armageddon::set_baseline_handler(ctx, baseline_lp_value)
+    }
+
+    /// Get Armageddon level, pause expiry and recovery target (read-only)
+    /// Returned via return data (no account deserialization needed)
+    pub fn get_armageddon_status(ctx: Context<EvaluateArmageddon>) -> Result<ArmageddonStatus> {
+        instructions::armageddon::get_status_handler(ctx)
+    }
+
+    /// Evaluate which Armageddon level an LP value warrants (read-only)
+    pub fn evaluate_armageddon_level(
+        ctx: Context<EvaluateArmageddon>,
+        current_lp_value: u64,
+    ) -> Result<u8> {
+        instructions::armageddon::evaluate_handler(ctx, current_lp_value)
+    }
+
+    /// Evaluate Armageddon level from pool reserves (read-only)
+    /// Price is lamports per token base unit, scaled by lp_value::PRICE_SCALE
+    pub fn evaluate_armageddon_from_reserves(
+        ctx: Context<EvaluateArmageddon>,
+        sol_reserve: u64,
+        token_reserve: u64,
+        token_price_in_sol: u64,
+    ) -> Result<u8> {
+        instructions::armageddon::evaluate_from_reserves_handler(
+            ctx,
+            sol_reserve,
+            token_reserve,
+            token_price_in_sol,
+        )
     }
 
     /// Trigger Armageddon mode
     /// Emergency response when LP drops significantly
+    /// Levels 1-2: admin or governance. Level 3 (trading pause): governance only
+    /// current_lp_value: observed LP value, recorded as the recovery reference
     pub fn trigger_armageddon(
         ctx: Context<TriggerArmageddon>,
         level: u8, // 1 = DEFCON 3, 2 = DEFCON 2, 3 = DEFCON 1
+        current_lp_value: u64,
     ) -> Result<()> {
-        instructions::armageddon::trigger_handler(ctx, level)
+        instructions::armageddon::trigger_handler(ctx, level, current_lp_value)
+    }
+
+    /// Recover from Armageddon (recovery authority only)
+    /// current_lp_value: observed LP value, must reach the recovery target
+    pub fn recover_from_armageddon(ctx: Context<RecoverArmageddon>, current_lp_value: u64) -> Result<()> {
+        instructions::armageddon::recover_handler(ctx, current_lp_value)
     }
 
-    /// Recover from Armageddon
-    pub fn recover_from_armageddon(ctx: Context<RecoverArmageddon>) -> Result<()> {
-        instructions::armageddon::recover_handler(ctx)
+    /// Set who can declare an Armageddon over (governance only)
+    pub fn set_recovery_authority(
+        ctx: Context<SetRecoveryAuthority>,
+        new_recovery_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::armageddon::set_recovery_authority_handler(ctx, new_recovery_authority)
+    }
+
+    /// Set LP recovery threshold (recovery authority or governance, 10000-20000 bps)
+    pub fn set_recovery_threshold(
+        ctx: Context<SetRecoveryThreshold>,
+        recovery_threshold_bps: u16,
+    ) -> Result<()> {
+        instructions::armageddon::set_recovery_threshold_handler(ctx, recovery_threshold_bps)
     }
 
     // =========================================================================
@@ -269,6 +646,44 @@ pub mod paradox_token {
         instructions::fees::distribute_handler(ctx)
     }
 
+    /// Preview fee distribution (read-only, returned via return data)
+    pub fn preview_distribution(
+        ctx: Context<PreviewDistribution>,
+        fee_amount: u64,
+    ) -> Result<(u64, u64, u64)> {
+        instructions::fees::preview_distribution_handler(ctx, fee_amount)
+    }
+
+    /// Burn fees from the fee vault (admin only)
+    /// Executes the burn share independently of full distribution
+    pub fn burn_fees(ctx: Context<BurnFees>, amount: u64) -> Result<()> {
+        instructions::fees::burn_fees_handler(ctx, amount)
+    }
+
+    /// Sweep fee vault dust below the dust threshold (admin only)
+    /// Sends to treasury if treasury accounts are passed, burns otherwise
+    pub fn sweep_fee_dust(ctx: Context<SweepFeeDust>) -> Result<()> {
+        instructions::fees::sweep_fee_dust_handler(ctx)
+    }
+
+    /// Harvest withheld fees and distribute atomically
+    /// Pass source token accounts as remaining_accounts
+    /// Pass harvester_token_account to receive the keeper reward (if configured)
+    pub fn harvest_and_distribute<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestAndDistribute<'info>>,
+    ) -> Result<()> {
+        instructions::fees::harvest_and_distribute_handler(ctx)
+    }
+    
+    /// Harvest withheld fees and split that harvest atomically
+    /// LP share to LP growth, burn share burned, treasury share to treasury
+    /// Pass source token accounts as remaining_accounts
+    pub fn harvest_to_growth<'info>(
+        ctx: Context<'_, '_, '_, 'info, HarvestToGrowth<'info>>,
+    ) -> Result<()> {
+        instructions::fees::harvest_to_growth_handler(ctx)
+    }
+
     // =========================================================================
     // LP LOCK (Progressive Timelock with Snapshot/Restore)
     // =========================================================================
@@ -285,32 +700,62 @@ pub mod paradox_token {
     // =========================================================================
 
     /// Create pool and lock LP atomically
+    /// Optionally pins all future withdrawals to a single recipient
+    /// and caps concurrent pending withdrawals below 3 (e.g. 1 = one at a time)
     pub fn create_pool_and_lock(
         ctx: Context<CreatePoolAndLock>,
         sol_amount: u64,
         token_amount: u64,
         timelock_seconds: Option<i64>,
         max_withdrawal_bps: Option<u16>,
+        allowed_recipient: Option<Pubkey>,
+        permanent_floor_bps: Option<u16>,
+        max_pending_allowed: Option<u8>,
     ) -> Result<()> {
         instructions::lp_lock::create_pool_and_lock_handler(
-            ctx, sol_amount, token_amount, timelock_seconds, max_withdrawal_bps
+            ctx, sol_amount, token_amount, timelock_seconds, max_withdrawal_bps, allowed_recipient,
+            permanent_floor_bps, max_pending_allowed,
         )
     }
 
     /// Take manual snapshot of LP state
-    pub fn take_lp_snapshot(
-        ctx: Context<TakeSnapshot>,
-        reason: [u8; 32],
+    /// `note` is a free-form label stored after the reason type byte
+    pub fn take_lp_snapshot<'info>(
+        ctx: Context<'_, '_, 'info, 'info, TakeSnapshot<'info>>,
+        reason: SnapshotReason,
+        note: [u8; 31],
         sol_reserve: u64,
         token_reserve: u64,
         total_supply: u64,
         holder_count: u32,
     ) -> Result<u64> {
         instructions::lp_lock::take_snapshot_handler(
-            ctx, reason, sol_reserve, token_reserve, total_supply, holder_count
+            ctx, reason, note, sol_reserve, token_reserve, total_supply, holder_count
         )
     }
 
+    /// Write a page of holder balances for an upcoming attested snapshot (admin only)
+    /// Pages chain via next_account; pass them to take_lp_snapshot to verify holder_count
+    pub fn write_holder_snapshot_page(
+        ctx: Context<WriteHolderSnapshotPage>,
+        snapshot_id: u64,
+        page_index: u16,
+        holders: Vec<HolderSnapshot>,
+        next_account: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::lp_lock::write_holder_snapshot_page_handler(
+            ctx, snapshot_id, page_index, holders, next_account
+        )
+    }
+
+    /// Invalidate snapshot so it can no longer be restored
+    pub fn invalidate_lp_snapshot(
+        ctx: Context<InvalidateSnapshot>,
+        snapshot_id: u64,
+    ) -> Result<()> {
+        instructions::lp_lock::invalidate_snapshot_handler(ctx, snapshot_id)
+    }
+
     /// Announce LP withdrawal (auto-takes snapshot, starts timelock)
     /// Timelock depends on current phase:
     ///   - Days 0-3: 12h
@@ -326,11 +771,14 @@ pub mod paradox_token {
     }
 
     /// Execute LP withdrawal (after timelock passes)
-    pub fn execute_lp_withdrawal(
-        ctx: Context<ExecuteWithdrawal>,
+    /// `nonce` must match the execution_nonce emitted at announcement
+    /// Hook-enabled mints: pass the transfer hook's extra accounts as remaining accounts
+    pub fn execute_lp_withdrawal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteWithdrawal<'info>>,
         slot: u8,
+        nonce: u64,
     ) -> Result<()> {
-        instructions::lp_lock::execute_withdrawal_handler(ctx, slot)
+        instructions::lp_lock::execute_withdrawal_handler(ctx, slot, nonce)
     }
 
     /// Cancel pending LP withdrawal
@@ -341,8 +789,20 @@ pub mod paradox_token {
         instructions::lp_lock::cancel_withdrawal_handler(ctx, slot)
     }
 
+    /// Emergency LP withdrawal (emergency multisig, within 15 min of lock, one-time)
+    /// For fixing a botched launch - bypasses the timelock
+    /// Hook-enabled mints: pass the transfer hook's extra accounts as remaining accounts
+    pub fn emergency_lp_withdrawal<'info>(
+        ctx: Context<'_, '_, '_, 'info, EmergencyLpWithdrawal<'info>>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::lp_lock::emergency_withdrawal_handler(ctx, amount, reason)
+    }
+
     /// Restore LP from snapshot (for relaunch)
     /// Restores LP to vault and marks snapshot as used
+    /// Snapshots past max age need a governance co-signature
     pub fn restore_from_snapshot(
         ctx: Context<RestoreFromSnapshot>,
         snapshot_id: u64,
@@ -351,15 +811,116 @@ pub mod paradox_token {
         instructions::lp_lock::restore_from_snapshot_handler(ctx, snapshot_id, lp_amount)
     }
 
+    /// Set max snapshot age for restore (governance only)
+    pub fn set_max_snapshot_age(
+        ctx: Context<SetMaxSnapshotAge>,
+        max_snapshot_age_seconds: u32,
+    ) -> Result<()> {
+        instructions::lp_lock::set_max_snapshot_age_handler(ctx, max_snapshot_age_seconds)
+    }
+
+    /// Set how long a withdrawal stays executable after its timelock (governance only)
+    /// Hours, 0 = never expires
+    pub fn set_execution_grace(
+        ctx: Context<SetExecutionGrace>,
+        execution_grace_hours: u16,
+    ) -> Result<()> {
+        instructions::lp_lock::set_execution_grace_handler(ctx, execution_grace_hours)
+    }
+
+    /// Enable the holder guard (governance only, can't be turned off afterwards)
+    /// Above `holder_guard_threshold` holders, each announcement is capped at
+    /// `holder_guard_max_bps` of the locked LP
+    pub fn init_holder_guard(
+        ctx: Context<InitHolderGuard>,
+        holder_guard_threshold: u32,
+        holder_guard_max_bps: u16,
+    ) -> Result<()> {
+        instructions::lp_lock::init_holder_guard_handler(ctx, holder_guard_threshold, holder_guard_max_bps)
+    }
+
+    /// Update the holder guard threshold / cap (governance only, threshold 0 = off)
+    pub fn set_holder_guard(
+        ctx: Context<SetHolderGuard>,
+        holder_guard_threshold: u32,
+        holder_guard_max_bps: u16,
+    ) -> Result<()> {
+        instructions::lp_lock::set_holder_guard_handler(ctx, holder_guard_threshold, holder_guard_max_bps)
+    }
+
+    /// Clear a withdrawal left unexecuted past its grace window (permissionless)
+    pub fn expire_withdrawal(
+        ctx: Context<ExpireWithdrawal>,
+        slot: u8,
+    ) -> Result<()> {
+        instructions::lp_lock::expire_withdrawal_handler(ctx, slot)
+    }
+
     /// Transfer LP lock admin (to DAO)
     pub fn transfer_lp_lock_admin(ctx: Context<TransferAdmin>) -> Result<()> {
         instructions::lp_lock::transfer_admin_handler(ctx)
     }
 
+    /// Rotate the LP lock emergency multisig (governance or current multisig)
+    pub fn update_emergency_multisig(ctx: Context<UpdateEmergencyMultisig>) -> Result<()> {
+        instructions::lp_lock::update_emergency_multisig_handler(ctx)
+    }
+
+    /// Cancel all pending withdrawals announced by a previous admin (admin only)
+    pub fn invalidate_inherited_withdrawals(ctx: Context<CancelWithdrawal>) -> Result<()> {
+        instructions::lp_lock::invalidate_inherited_withdrawals_handler(ctx)
+    }
+
     /// Get LP lock status
     pub fn get_lp_lock_status(ctx: Context<GetLockStatus>) -> Result<()> {
         instructions::lp_lock::get_lock_status_handler(ctx)
     }
+
+    /// Get current phase, required timelock and days to next phase
+    /// Returned via return data (no account deserialization needed)
+    pub fn get_phase_info(ctx: Context<GetLockStatus>) -> Result<PhaseInfo> {
+        instructions::lp_lock::get_phase_info_handler(ctx)
+    }
+
+    /// Persist the current time-based phase (permissionless)
+    /// Emits LpLockPhaseAdvanced when entering Stabilization / Permanent
+    pub fn advance_phase(ctx: Context<AdvancePhase>) -> Result<()> {
+        instructions::lp_lock::advance_phase_handler(ctx)
+    }
+
+    /// Get active pending withdrawals with timings via return data
+    pub fn get_pending_withdrawals(ctx: Context<GetLockStatus>) -> Result<Vec<PendingWithdrawalView>> {
+        instructions::lp_lock::get_pending_withdrawals_handler(ctx)
+    }
+
+    /// Close fully withdrawn LP lock and reclaim rent
+    /// `force` is required while a restorable snapshot still exists
+    pub fn close_lp_lock(ctx: Context<CloseLpLock>, force: bool) -> Result<()> {
+        instructions::lp_lock::close_lp_lock_handler(ctx, force)
+    }
+
+    // =========================================================================
+    // RESCUE
+    // =========================================================================
+
+    /// Move tokens of an unrelated mint out of a vault PDA's account (admin only)
+    /// vault: 0 = fee vault, 1 = treasury, 2 = LP lock. The configured mint is refused
+    pub fn rescue_foreign_tokens(
+        ctx: Context<RescueForeignTokens>,
+        vault: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::rescue::rescue_foreign_tokens_handler(ctx, vault, amount)
+    }
+
+    // =========================================================================
+    // MIGRATION
+    // =========================================================================
+
+    /// Bump a state account to the current layout version (admin only)
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate::migrate_account_handler(ctx)
+    }
 }
 
 // =============================================================================
@@ -466,6 +1027,201 @@ pub enum ParadoxError {
 
     #[msg("Pool not initialized")]
     PoolNotInitialized,
+
+    #[msg("Recipient not allowed for this LP lock")]
+    RecipientNotAllowed,
+
+    #[msg("Cannot announce withdrawal within 1h of a cancellation")]
+    AnnouncementCooldownActive,
+
+    #[msg("LP lock must be fully withdrawn with no pending withdrawals")]
+    LpLockNotClosable,
+
+    #[msg("Restorable snapshot exists (use force to close anyway)")]
+    RestorableSnapshotExists,
+
+    #[msg("Vesting not complete")]
+    VestingNotComplete,
+
+    #[msg("Vault token account not empty")]
+    VaultNotEmpty,
+
+    #[msg("Timelock can only be lengthened")]
+    TimelockCannotDecrease,
+
+    #[msg("Unlock rate out of allowed range (100-2000 bps)")]
+    InvalidUnlockRate,
+
+    #[msg("Withdrawal nonce does not match pending announcement")]
+    StaleWithdrawalNonce,
+
+    #[msg("Snapshot not found or already invalid")]
+    SnapshotNotFound,
+
+    #[msg("Governance signature required")]
+    GovernanceRequired,
+
+    #[msg("Baseline LP value not set")]
+    BaselineNotSet,
+
+    #[msg("Thresholds must be increasing (l1 < l2 < l3 <= 10000 bps)")]
+    InvalidThresholds,
+
+    #[msg("Account not initialized")]
+    NotInitialized,
+
+    #[msg("Account already at current version")]
+    AlreadyMigrated,
+
+    #[msg("Unknown account type")]
+    InvalidAccountType,
+
+    #[msg("Reason must be valid UTF-8 and within the length limit")]
+    InvalidReason,
+
+    #[msg("Invalid vesting schedule: require vesting_seconds > cliff_seconds >= 0")]
+    InvalidVestingSchedule,
+
+    #[msg("Liquid at TGE exceeds total allocation")]
+    InvalidLiquidAmount,
+
+    #[msg("Fee vault balance is not below the dust threshold")]
+    NotDust,
+
+    #[msg("Vault token account balance is insufficient")]
+    InsufficientVaultBalance,
+
+    #[msg("Vesting schedule can only be tightened, never shortened")]
+    VestingScheduleCannotShorten,
+
+    #[msg("Withdrawal would breach the permanent liquidity floor")]
+    LiquidityFloorBreached,
+
+    #[msg("Liquidity floor must be <= 10000 bps")]
+    InvalidLiquidityFloor,
+
+    #[msg("Harvester reward exceeds maximum")]
+    InvalidHarvesterReward,
+
+    #[msg("LP growth paused: price dropped too far since last growth")]
+    GrowthPausedByPriceDrop,
+
+    #[msg("A fee change is already pending - cancel it first")]
+    FeeChangeAlreadyPending,
+
+    #[msg("Large withdrawal needs more approver signatures")]
+    InsufficientApprovals,
+
+    #[msg("Invalid approver configuration")]
+    InvalidApprovers,
+
+    #[msg("Already approved")]
+    AlreadyApproved,
+
+    #[msg("Fee vault and token program must be Token-2022")]
+    FeeVaultNotToken2022,
+
+    #[msg("An unlock request is already pending - execute it first")]
+    UnlockAlreadyPending,
+
+    #[msg("Invalid recovery authority")]
+    InvalidRecoveryAuthority,
+
+    #[msg("Max pending withdrawals must be between 1 and 3")]
+    InvalidMaxPendingWithdrawals,
+
+    #[msg("Invalid fee bounds: require 0 <= min <= max <= 1000 and max > 0")]
+    InvalidFeeBounds,
+
+    #[msg("Invalid unlock split: split_bps must be <= 10000 with a secondary account")]
+    InvalidSplit,
+
+    #[msg("Mint authority must be the LP growth PDA")]
+    MintAuthorityMismatch,
+
+    #[msg("Snapshot is older than max snapshot age - governance override required")]
+    SnapshotTooOld,
+
+    #[msg("Asserted holder count does not match holder snapshot pages")]
+    HolderCountMismatch,
+    
+    #[msg("Withdrawal expired - grace window after timelock has passed")]
+    WithdrawalExpired,
+    
+    #[msg("Withdrawal has not expired")]
+    WithdrawalNotExpired,
+    
+    #[msg("Recipient token account is not the recipient's ATA for this mint")]
+    InvalidRecipientAta,
+    
+    #[msg("Recipient, associated token and system programs are required to create the ATA")]
+    RecipientAtaAccountsMissing,
+    
+    #[msg("Invalid fee mode (0 = net, 1 = gross-up)")]
+    InvalidFeeMode,
+    
+    #[msg("Vault surplus cannot cover the transfer fee gross-up")]
+    FeeGrossUpNotCovered,
+    
+    #[msg("A DAO withdrawal is already pending - execute or cancel it first")]
+    WithdrawalAlreadyPending,
+    
+    #[msg("Recovery threshold must be between 10000 and 20000 bps")]
+    InvalidRecoveryThreshold,
+    
+    #[msg("Emergency multisig cannot be the default address")]
+    InvalidEmergencyMultisig,
+    
+    #[msg("Meteora DLMM growth is not enabled in this build (meteora feature)")]
+    MeteoraNotEnabled,
+    
+    #[msg("LP growth cooldown must be at least 1 second")]
+    InvalidGrowthCooldown,
+    
+    #[msg("Cannot rescue the configured mint (or the LP lock's LP token)")]
+    CannotRescueConfiguredMint,
+    
+    #[msg("Invalid rescue vault or vault authority")]
+    InvalidRescueVault,
+    
+    #[msg("Fee distribution does not match the amount pulled from the fee vault")]
+    DistributionMismatch,
+    
+    #[msg("Recipient is not on the treasury allowlist")]
+    RecipientNotAllowlisted,
+    
+    #[msg("Treasury allowlist is full")]
+    AllowlistFull,
+    
+    #[msg("Invalid allowlist recipient (default, duplicate or not listed)")]
+    InvalidAllowlistRecipient,
+    
+    #[msg("Vesting vault did not receive exactly the locked amount")]
+    VestingFundingMismatch,
+    
+    #[msg("Fee execution window below minimum (1 hour)")]
+    InvalidFeeExecutionWindow,
+    
+    #[msg("Fee change execution window closed - cancel and re-announce")]
+    FeeChangeWindowClosed,
+    
+    #[msg("Harvested amount does not match the mint's withheld fees")]
+    HarvestMismatch,
+    
+    #[msg("Invalid holder guard: max bps must be 1-10000")]
+    InvalidHolderGuard,
+    
+    #[msg("Withdrawal exceeds the holder guard cap")]
+    HolderGuardLimitExceeded,
+    
+    #[msg("Illegal LP lock status transition")]
+    InvalidStatusTransition,
+    
+    #[msg("Invalid LP pool weights: must sum to 10000 with a share for every pool")]
+    InvalidPoolWeights,
+    
+    #[msg("Governance must be a separate key from admin")]
+    InvalidGovernance,
 }
 
 // =============================================================================
@@ -479,6 +1235,8 @@ pub struct TokenConfigInitialized {
     pub lp_share_bps: u16,
     pub burn_share_bps: u16,
     pub treasury_share_bps: u16,
+    pub min_fee_bps: u16,
+    pub max_fee_bps: u16,
 }
 
 #[event]
@@ -502,6 +1260,24 @@ pub struct FeeChangeCancelled {
     pub cancelled_fee_bps: u16,
 }
 
+#[event]
+pub struct FeeSharesUpdated {
+    pub mint: Pubkey,
+    pub old_lp_share_bps: u16,
+    pub old_burn_share_bps: u16,
+    pub old_treasury_share_bps: u16,
+    pub new_lp_share_bps: u16,
+    pub new_burn_share_bps: u16,
+    pub new_treasury_share_bps: u16,
+}
+
+#[event]
+pub struct MintAuthorityAssignedToGrowth {
+    pub mint: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
 #[event]
 pub struct LpGrowthInitialized {
     pub mint: Pubkey,
@@ -515,6 +1291,24 @@ pub struct LpGrowthExecuted {
     pub sol_added: u64,
     pub tokens_minted: u64,
     pub new_lp_value: u64,
+    pub pending_token_fees: u64,
+}
+
+/// LP share fee tokens added to the growth manager (accumulated_token_fees)
+/// The SOL side (accumulated_sol vs. min_fee_threshold) is tracked by FeesSwappedToSol
+#[event]
+pub struct LpGrowthFeesAccumulated {
+    pub mint: Pubkey,
+    pub added: u64,
+    pub new_total: u64,
+}
+
+#[event]
+pub struct FeesSwappedToSol {
+    pub mint: Pubkey,
+    pub tokens_swapped: u64,
+    pub sol_received: u64,
+    pub accumulated_sol: u64,
 }
 
 #[event]
@@ -530,6 +1324,16 @@ pub struct LpGrowthUnlocked {
     pub unlocked_by: Pubkey,
 }
 
+#[event]
+pub struct LpGrowthPoolsUpdated {
+    pub mint: Pubkey,
+    pub lp_pool: Pubkey,
+    pub primary_weight_bps: u16,
+    pub secondary_lp_pool: Pubkey,
+    pub secondary_weight_bps: u16,
+    pub updated_by: Pubkey,
+}
+
 #[event]
 pub struct DevVestingInitialized {
     pub dev: Pubkey,
@@ -545,6 +1349,12 @@ pub struct DevUnlockRequested {
     pub dev: Pubkey,
     pub amount: u64,
     pub unlock_time: i64,
+    /// Vested by schedule so far (0 before the cliff)
+    pub vested_total: u64,
+    /// Still locked in the vault (pending amount included)
+    pub remaining_locked: u64,
+    /// vested_total / total_locked in bps
+    pub percent_vested_bps: u16,
 }
 
 #[event]
@@ -552,6 +1362,49 @@ pub struct DevUnlockExecuted {
     pub dev: Pubkey,
     pub amount: u64,
     pub remaining_locked: u64,
+    /// Vested by schedule so far (0 before the cliff)
+    pub vested_total: u64,
+    /// vested_total / total_locked in bps
+    pub percent_vested_bps: u16,
+    /// Portion sent to secondary_destination (0 = no split)
+    pub split_amount: u64,
+    pub secondary_destination: Pubkey,
+    /// Tokens that left the vault (both legs)
+    pub gross_amount: u64,
+    /// Tokens received after transfer fees (both legs)
+    pub net_amount: u64,
+}
+
+#[event]
+pub struct DevVestingTightened {
+    pub dev: Pubkey,
+    pub mint: Pubkey,
+    pub cliff_seconds: i64,
+    pub cooldown_seconds: i64,
+    pub timelock_seconds: i64,
+}
+
+#[event]
+pub struct DevVestingClosed {
+    pub dev: Pubkey,
+    pub mint: Pubkey,
+    pub total_unlocked: u64,
+}
+
+#[event]
+pub struct DaoTreasuryInitialized {
+    pub governance: Pubkey,
+    pub mint: Pubkey,
+    pub max_spend_bps_per_period: u16,
+    pub period_seconds: i64,
+    pub timelock_seconds: i64,
+}
+
+#[event]
+pub struct TreasuryTimelockUpdated {
+    pub mint: Pubkey,
+    pub old_timelock_seconds: i64,
+    pub new_timelock_seconds: i64,
 }
 
 #[event]
@@ -561,17 +1414,106 @@ pub struct DaoWithdrawalProposed {
     pub recipient: Pubkey,
     pub reason: String,
     pub execute_after: i64,
+    pub proposal_nonce: u64,
 }
 
 #[event]
 pub struct DaoWithdrawalExecuted {
     pub recipient: Pubkey,
     pub amount: u64,
+    /// Tokens that left the treasury
+    pub gross_amount: u64,
+    /// Tokens received after transfer fees
+    pub net_amount: u64,
+    pub proposal_nonce: u64,
+}
+
+#[event]
+pub struct DaoWithdrawalCancelled {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub proposal_nonce: u64,
+}
+
+#[event]
+pub struct PayoutFeeModeUpdated {
+    pub vault: Pubkey,
+    pub mint: Pubkey,
+    pub old_mode: u8,
+    pub new_mode: u8,
+}
+
+#[event]
+pub struct TreasuryGovernanceTransferStarted {
+    pub mint: Pubkey,
+    pub current_governance: Pubkey,
+    pub pending_governance: Pubkey,
+}
+
+#[event]
+pub struct TreasuryGovernanceTransferred {
+    pub mint: Pubkey,
+    pub old_governance: Pubkey,
+    pub new_governance: Pubkey,
+}
+
+#[event]
+pub struct TreasuryApprovalsConfigured {
+    pub mint: Pubkey,
+    pub large_withdrawal_threshold: u64,
+    pub required_approvals: u8,
+    pub approvers: Vec<Pubkey>,
+}
+
+#[event]
+pub struct TreasuryAllowlistUpdated {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    /// true = added, false = removed
+    pub added: bool,
+    pub count: u8,
+}
+
+#[event]
+pub struct DaoWithdrawalApproved {
+    pub mint: Pubkey,
+    pub approver: Pubkey,
+    pub amount: u64,
+    pub approvals: u8,
+    pub required_approvals: u8,
+}
+
+#[event]
+pub struct TreasuryDeposited {
+    pub mint: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub received: u64,
+    pub balance: u64,
+}
+
+#[event]
+pub struct ArmageddonInitialized {
+    pub token_config: Pubkey,
+    pub threshold_l1_bps: u16,
+    pub threshold_l2_bps: u16,
+    pub threshold_l3_bps: u16,
+}
+
+#[event]
+pub struct BaselineLpValueSet {
+    pub token_config: Pubkey,
+    pub old_baseline: u64,
+    pub new_baseline: u64,
+    pub set_by: Pubkey,
 }
 
 #[event]
 pub struct ArmageddonTriggered {
     pub level: u8,
+    /// Configured threshold for this level (percent)
+    pub threshold_percent: u8,
+    /// Observed drop from baseline (percent, 0 = not supplied)
     pub lp_drop_percent: u8,
     pub response: String,
 }
@@ -579,9 +1521,27 @@ pub struct ArmageddonTriggered {
 #[event]
 pub struct ArmageddonRecovered {
     pub previous_level: u8,
+    /// Fee after recovery (pre-trigger fee when a snapshot existed)
+    pub restored_fee_bps: u16,
+    /// Recovery threshold in effect, as % of the LP value at trigger
     pub lp_recovery_percent: u8,
 }
 
+#[event]
+pub struct ArmageddonRecoveryAuthorityUpdated {
+    pub token_config: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RecoveryThresholdUpdated {
+    pub token_config: Pubkey,
+    pub old_threshold_bps: u16,
+    pub new_threshold_bps: u16,
+    pub updated_by: Pubkey,
+}
+
 #[event]
 pub struct FeesDistributed {
     pub total_fees: u64,
@@ -590,6 +1550,26 @@ pub struct FeesDistributed {
     pub to_treasury: u64,
 }
 
+#[event]
+pub struct FeesBurned {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct HarvesterRewardUpdated {
+    pub mint: Pubkey,
+    pub old_reward_bps: u16,
+    pub new_reward_bps: u16,
+}
+
+#[event]
+pub struct FeeDustSwept {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub to_treasury: bool,
+}
+
 // LP Lock Events
 
 #[event]
@@ -600,6 +1580,25 @@ pub struct LpLockCreated {
     pub timelock_seconds: i64,
     pub max_withdrawal_bps: u16,
     pub admin: Pubkey,
+    pub allowed_recipient: Pubkey,
+    pub permanent_floor_bps: u16,
+    pub max_pending_allowed: u8,
+}
+
+#[event]
+pub struct LpLockPhaseAdvanced {
+    pub mint: Pubkey,
+    pub old_phase: u8,
+    pub new_phase: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SnapshotInvalidated {
+    pub mint: Pubkey,
+    pub snapshot_id: u64,
+    pub invalidated_by: Pubkey,
+    pub latest_restorable_snapshot: u64,
 }
 
 #[event]
@@ -611,6 +1610,7 @@ pub struct LpWithdrawalAnnounced {
     pub announced_at: i64,
     pub execute_after: i64,
     pub slot: u8,
+    pub execution_nonce: u64,
 }
 
 #[event]
@@ -630,6 +1630,18 @@ pub struct LpWithdrawalCancelled {
     pub recipient: Pubkey,
     pub cancelled_by: Pubkey,
     pub slot: u8,
+    pub announcements_count: u32,
+    pub cancellations_count: u32,
+}
+
+#[event]
+pub struct LpWithdrawalExpired {
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub expired_by: Pubkey,
+    pub slot: u8,
+    pub execute_after: i64,
 }
 
 #[event]
@@ -641,6 +1653,33 @@ pub struct LpLockFinalized {
     pub finalized_by: Pubkey,
 }
 
+#[event]
+pub struct LpLockClosed {
+    pub mint: Pubkey,
+    pub closed_by: Pubkey,
+    pub rent_recipient: Pubkey,
+    pub total_withdrawn: u64,
+    pub forced: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LpLockAdminTransferred {
+    pub mint: Pubkey,
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LpLockEmergencyMultisigUpdated {
+    pub mint: Pubkey,
+    pub old_multisig: Pubkey,
+    pub new_multisig: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct LpEmergencyWithdrawal {
     pub mint: Pubkey,
@@ -650,11 +1689,29 @@ pub struct LpEmergencyWithdrawal {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub mint: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+}
+
 #[event]
 pub struct FeesHarvested {
     pub mint: Pubkey,
     pub amount: u64,
     pub harvested_by: Pubkey,
     pub destination: Pubkey,
+    pub harvester_reward: u64,
 }
 
+#[event]
+pub struct ForeignTokensRescued {
+    pub mint: Pubkey,
+    pub vault: u8,
+    pub foreign_mint: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub rescued_by: Pubkey,
+}
@@ -0,0 +1,81 @@
+/**
+ * LP Value Helpers
+ * 
+ * Shared "current LP value" math used by Armageddon evaluation and LP growth.
+ * 
+ * PRICE SOURCE ASSUMPTION:
+ * `token_price_in_sol` is lamports per token base unit, fixed-point scaled by
+ * PRICE_SCALE. It is expected to be the pool's own spot price (see
+ * `price_from_reserves`) or an oracle price converted to the same scale.
+ * Spot prices can be moved within a single transaction - callers that gate
+ * privileged actions on this value should prefer a TWAP / oracle source.
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+
+use crate::ParadoxError;
+
+/// Fixed-point scale for token prices (1e9 = 1 lamport per base unit)
+pub const PRICE_SCALE: u128 = 1_000_000_000;
+
+/// Total pool value in lamports: sol_reserve + token_reserve * price
+/// Uses u128 intermediate calculations to prevent overflow
+pub fn lp_value_from_reserves(
+    sol_reserve: u64,
+    token_reserve: u64,
+    token_price_in_sol: u64,
+) -> Result<u64> {
+    let token_side = (token_reserve as u128)
+        .checked_mul(token_price_in_sol as u128)
+        .ok_or(ParadoxError::MathOverflow)?
+        / PRICE_SCALE;
+    
+    let total = (sol_reserve as u128)
+        .checked_add(token_side)
+        .ok_or(ParadoxError::MathOverflow)?;
+    
+    u64::try_from(total).map_err(|_| error!(ParadoxError::MathOverflow))
+}
+
+/// Pool spot price (lamports per token base unit, PRICE_SCALE fixed-point)
+/// Returns 0 for an empty token side - callers treat 0 as "unknown price"
+pub fn price_from_reserves(sol_reserve: u64, token_reserve: u64) -> Result<u64> {
+    if token_reserve == 0 {
+        return Ok(0);
+    }
+    
+    let price = (sol_reserve as u128)
+        .checked_mul(PRICE_SCALE)
+        .ok_or(ParadoxError::MathOverflow)?
+        / token_reserve as u128;
+    
+    u64::try_from(price).map_err(|_| error!(ParadoxError::MathOverflow))
+}
+
+/// LP tokens received for depositing (sol_in, token_in) into a constant-product pool
+/// Pools mint the smaller of the two proportional shares; 0 for an empty pool
+pub fn lp_tokens_for_deposit(
+    sol_in: u64,
+    token_in: u64,
+    sol_reserve: u64,
+    token_reserve: u64,
+    lp_supply: u64,
+) -> Result<u64> {
+    if sol_reserve == 0 || token_reserve == 0 || lp_supply == 0 {
+        return Ok(0);
+    }
+    
+    let from_sol = (sol_in as u128)
+        .checked_mul(lp_supply as u128)
+        .ok_or(ParadoxError::MathOverflow)?
+        / sol_reserve as u128;
+    let from_token = (token_in as u128)
+        .checked_mul(lp_supply as u128)
+        .ok_or(ParadoxError::MathOverflow)?
+        / token_reserve as u128;
+    
+    u64::try_from(from_sol.min(from_token)).map_err(|_| error!(ParadoxError::MathOverflow))
+}
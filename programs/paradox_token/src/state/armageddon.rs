@@ -8,6 +8,23 @@
  */
 
 use anchor_lang::prelude::*;
+use super::VersionedAccount;
+use crate::ParadoxError;
+
+/// Default DEFCON 3 threshold: 50% LP drop
+pub const DEFAULT_THRESHOLD_L1_BPS: u16 = 5000;
+
+/// Default DEFCON 2 threshold: 75% LP drop
+pub const DEFAULT_THRESHOLD_L2_BPS: u16 = 7500;
+
+/// Default DEFCON 1 threshold: 90% LP drop
+pub const DEFAULT_THRESHOLD_L3_BPS: u16 = 9000;
+
+/// Lowest recovery bar: back to the trigger value (100%)
+pub const MIN_RECOVERY_THRESHOLD_BPS: u16 = 10_000;
+
+/// Highest recovery bar: double the trigger value (200%)
+pub const MAX_RECOVERY_THRESHOLD_BPS: u16 = 20_000;
 
 /// Armageddon Mode levels
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -28,6 +45,29 @@ impl Default for ArmageddonLevel {
     }
 }
 
+/// Machine-readable Armageddon data for status banners (returned via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ArmageddonStatus {
+    /// Current level (0 = normal, 1-3 = DEFCON 3..1)
+    pub level: u8,
+    /// Pause flag as stored (set at DEFCON 1, cleared on recovery)
+    pub trading_paused: bool,
+    /// Pause flag with max_pause_duration applied (false once expired)
+    pub pause_active: bool,
+    /// Timestamp when the pause expires (0 = not paused)
+    pub pause_expires_at: i64,
+    /// Timestamp when Armageddon was triggered (0 = never)
+    pub triggered_at: i64,
+    /// LP value when triggered
+    pub lp_value_at_trigger: u64,
+    /// Baseline LP value
+    pub baseline_lp_value: u64,
+    /// LP recovery threshold (bps of trigger value)
+    pub recovery_threshold_bps: u16,
+    /// LP value needed to recover (lp_value_at_trigger * recovery_threshold_bps / 10000)
+    pub recovery_target: u64,
+}
+
 /// Armageddon State account
 #[account]
 pub struct ArmageddonState {
@@ -50,6 +90,7 @@ pub struct ArmageddonState {
     pub trigger_authority: Pubkey,
     
     /// Authority that can recover from Armageddon
+    /// May differ from trigger_authority (declaring an emergency ≠ declaring it over)
     pub recovery_authority: Pubkey,
     
     /// LP recovery threshold (in bps, e.g., 12000 = 120% of trigger value)
@@ -70,8 +111,34 @@ pub struct ArmageddonState {
     /// Bump seed for PDA
     pub bump: u8,
     
+    /// DEFCON 3 LP drop threshold (bps of baseline)
+    pub threshold_l1_bps: u16,
+    
+    /// DEFCON 2 LP drop threshold (bps of baseline)
+    pub threshold_l2_bps: u16,
+    
+    /// DEFCON 1 LP drop threshold (bps of baseline)
+    pub threshold_l3_bps: u16,
+    
+    /// Set once by init handler
+    pub is_initialized: bool,
+    
+    /// Account layout version
+    pub version: u8,
+    
+    /// Fee in effect before the trigger (restored on recovery)
+    pub pre_armageddon_fee_bps: u16,
+    
+    /// Shares in effect before the trigger (restored on recovery)
+    pub pre_armageddon_lp_share_bps: u16,
+    pub pre_armageddon_burn_share_bps: u16,
+    pub pre_armageddon_treasury_share_bps: u16,
+    
+    /// Pre-trigger values above are set (false on accounts triggered before they existed)
+    pub pre_armageddon_saved: bool,
+    
     /// Reserved for future use
-    pub reserved: [u8; 32],
+    pub reserved: [u8; 15],
 }
 
 impl ArmageddonState {
@@ -89,30 +156,120 @@ impl ArmageddonState {
         1 +  // trading_paused
         8 +  // max_pause_duration
         1 +  // bump
-        32;  // reserved
+        2 +  // threshold_l1_bps
+        2 +  // threshold_l2_bps
+        2 +  // threshold_l3_bps
+        1 +  // is_initialized
+        1 +  // version
+        2 +  // pre_armageddon_fee_bps
+        2 +  // pre_armageddon_lp_share_bps
+        2 +  // pre_armageddon_burn_share_bps
+        2 +  // pre_armageddon_treasury_share_bps
+        1 +  // pre_armageddon_saved
+        15;  // reserved
+    
+    /// Set up by its init handler. Accounts created before the flag existed
+    /// read it as 0, so a nonzero `token_config` (always written at init) also counts
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized || self.token_config != Pubkey::default()
+    }
     
     /// Check if LP has recovered enough to exit Armageddon
-    /// Uses u128 intermediate calculations to prevent overflow
-    pub fn can_recover(&self, current_lp_value: u64) -> bool {
+    /// Errors with MathOverflow if the recovery target doesn't fit in u64
+    pub fn can_recover(&self, current_lp_value: u64) -> Result<bool> {
         if self.level == 0 {
-            return false; // Not in Armageddon
+            return Ok(false); // Not in Armageddon
         }
         
-        let recovery_target = ((self.lp_value_at_trigger as u128)
-            .saturating_mul(self.recovery_threshold_bps as u128)
-            .checked_div(10_000)
-            .unwrap_or(0)) as u64;
+        if self.baseline_lp_value == 0 {
+            return Ok(false); // No reference point
+        }
         
-        current_lp_value >= recovery_target
+        Ok(current_lp_value >= self.recovery_target()?)
     }
     
-    /// Get DEFCON level thresholds
-    pub fn get_threshold(level: u8) -> u8 {
-        match level {
-            1 => 50,  // DEFCON 3: 50% drop
-            2 => 75,  // DEFCON 2: 75% drop
-            3 => 90,  // DEFCON 1: 90% drop
-            _ => 0,
+    /// LP value needed to exit Armageddon
+    /// Uses u128 intermediate calculations; a target above u64::MAX is an error, not a truncation
+    pub fn recovery_target(&self) -> Result<u64> {
+        let target = (self.lp_value_at_trigger as u128)
+            .checked_mul(self.recovery_threshold_bps as u128)
+            .ok_or(ParadoxError::MathOverflow)?
+            / 10_000;
+        
+        u64::try_from(target).map_err(|_| error!(ParadoxError::MathOverflow))
+    }
+    
+    /// Timestamp when the trading pause expires (0 = not paused)
+    pub fn pause_expires_at(&self) -> i64 {
+        if !self.trading_paused {
+            return 0;
+        }
+        self.triggered_at.saturating_add(self.max_pause_duration)
+    }
+    
+    /// Status banner data at `current_time`
+    pub fn get_status(&self, current_time: i64) -> Result<ArmageddonStatus> {
+        let pause_expires_at = self.pause_expires_at();
+        
+        Ok(ArmageddonStatus {
+            level: self.level,
+            trading_paused: self.trading_paused,
+            pause_active: self.trading_paused && current_time < pause_expires_at,
+            pause_expires_at,
+            triggered_at: self.triggered_at,
+            lp_value_at_trigger: self.lp_value_at_trigger,
+            baseline_lp_value: self.baseline_lp_value,
+            recovery_threshold_bps: self.recovery_threshold_bps,
+            recovery_target: self.recovery_target()?,
+        })
+    }
+    
+    /// Validate thresholds are strictly increasing and within 100%
+    pub fn validate_thresholds(l1_bps: u16, l2_bps: u16, l3_bps: u16) -> bool {
+        l1_bps > 0 && l1_bps < l2_bps && l2_bps < l3_bps && l3_bps <= 10_000
+    }
+    
+    /// Get configured DEFCON level threshold (LP drop in bps)
+    /// Unset (0) thresholds on accounts created before they existed use the defaults
+    pub fn get_threshold(&self, level: u8) -> u16 {
+        let (stored, default) = match level {
+            1 => (self.threshold_l1_bps, DEFAULT_THRESHOLD_L1_BPS), // DEFCON 3
+            2 => (self.threshold_l2_bps, DEFAULT_THRESHOLD_L2_BPS), // DEFCON 2
+            3 => (self.threshold_l3_bps, DEFAULT_THRESHOLD_L3_BPS), // DEFCON 1
+            _ => return 0,
+        };
+        
+        if stored == 0 { default } else { stored }
+    }
+    
+    /// Observed LP drop from baseline in bps (0 if no baseline or no drop)
+    /// Uses u128 intermediate calculations to prevent overflow
+    pub fn drop_bps(&self, current_lp_value: u64) -> u64 {
+        if self.baseline_lp_value == 0 || current_lp_value >= self.baseline_lp_value {
+            return 0;
+        }
+        
+        ((self.baseline_lp_value - current_lp_value) as u128)
+            .saturating_mul(10_000)
+            .checked_div(self.baseline_lp_value as u128)
+            .unwrap_or(0) as u64
+    }
+    
+    /// Evaluate which level the current LP value warrants (0 = normal)
+    pub fn evaluate_level(&self, current_lp_value: u64) -> u8 {
+        let drop_bps = self.drop_bps(current_lp_value);
+        if drop_bps == 0 {
+            return 0;
+        }
+        
+        if drop_bps >= self.get_threshold(3) as u64 {
+            3
+        } else if drop_bps >= self.get_threshold(2) as u64 {
+            2
+        } else if drop_bps >= self.get_threshold(1) as u64 {
+            1
+        } else {
+            0
         }
     }
     
@@ -127,3 +284,58 @@ impl ArmageddonState {
     }
 }
 
+impl VersionedAccount for ArmageddonState {
+    fn version(&self) -> u8 {
+        self.version
+    }
+    
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+    
+    fn owner_key(&self) -> Pubkey {
+        self.token_config
+    }
+    
+    fn populate_new_fields(&mut self, old_version: u8) {
+        if old_version == 0 {
+            self.is_initialized = true;
+            if self.threshold_l1_bps == 0 {
+                self.threshold_l1_bps = DEFAULT_THRESHOLD_L1_BPS;
+            }
+            if self.threshold_l2_bps == 0 {
+                self.threshold_l2_bps = DEFAULT_THRESHOLD_L2_BPS;
+            }
+            if self.threshold_l3_bps == 0 {
+                self.threshold_l3_bps = DEFAULT_THRESHOLD_L3_BPS;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn new_state() -> ArmageddonState {
+        ArmageddonState::try_deserialize_unchecked(&mut &vec![0u8; ArmageddonState::LEN][..]).unwrap()
+    }
+    
+    #[test]
+    fn recovery_target_near_u64_max() {
+        let mut state = new_state();
+        state.lp_value_at_trigger = u64::MAX;
+        
+        // 100% of the trigger value still fits
+        state.recovery_threshold_bps = MIN_RECOVERY_THRESHOLD_BPS;
+        assert_eq!(state.recovery_target().unwrap(), u64::MAX);
+        
+        // 120% doesn't - an error, not a truncated target
+        state.recovery_threshold_bps = 12_000;
+        assert_eq!(state.recovery_target().unwrap_err(), ParadoxError::MathOverflow.into());
+        
+        state.level = 1;
+        state.baseline_lp_value = u64::MAX;
+        assert_eq!(state.can_recover(u64::MAX).unwrap_err(), ParadoxError::MathOverflow.into());
+    }
+}
@@ -9,6 +9,28 @@
  */
 
 use anchor_lang::prelude::*;
+use super::VersionedAccount;
+
+/// Default circuit breaker: pause growth if price fell more than 20% since last growth
+pub const DEFAULT_MAX_MINT_RATIO_BPS: u16 = 2000;
+
+/// Minimum cooldown between growths: at most one growth per timestamp
+/// (0 would allow repeated growth within the same block)
+pub const MIN_GROWTH_COOLDOWN_SECONDS: i64 = 1;
+
+/// Pools the SOL side of growth can be split across (lp_pool + secondary_lp_pool)
+pub const MAX_LP_POOLS: usize = 2;
+
+/// Simulated growth for keepers (returned via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct LpGrowthQuote {
+    /// SOL the next growth would add (accumulated_sol)
+    pub sol_to_add: u64,
+    /// Tokens that would be minted to pair with it
+    pub tokens_to_mint: u64,
+    /// LP tokens the deposit would receive at current reserves (0 = unknown)
+    pub estimated_lp_out: u64,
+}
 
 /// LP Growth Manager account
 /// Controls automatic LP growth from accumulated fees
@@ -20,7 +42,7 @@ pub struct LpGrowthManager {
     /// LP pool address (Raydium/Orca/Meteora)
     pub lp_pool: Pubkey,
     
-    /// Account where SOL fees accumulate
+    /// Token account where the LP share of fees (PDOX) accumulates
     pub fee_accumulation_account: Pubkey,
     
     /// Authority that can trigger LP growth (usually this PDA)
@@ -29,7 +51,7 @@ pub struct LpGrowthManager {
     /// Minimum SOL required to trigger growth
     pub min_fee_threshold: u64,
     
-    /// Cooldown between growth executions (seconds)
+    /// Cooldown between growth executions (seconds, >= MIN_GROWTH_COOLDOWN_SECONDS)
     pub cooldown_seconds: i64,
     
     /// Timestamp of last growth execution
@@ -41,8 +63,8 @@ pub struct LpGrowthManager {
     /// Total tokens minted for LP (lifetime)
     pub total_tokens_minted: u64,
     
-    /// Current accumulated fees waiting to be used
-    pub accumulated_fees: u64,
+    /// SOL (lamports) from swapped fees, waiting to be added to LP
+    pub accumulated_sol: u64,
     
     /// Is LP growth locked (emergency)
     pub is_locked: bool,
@@ -53,8 +75,32 @@ pub struct LpGrowthManager {
     /// Bump seed for PDA
     pub bump: u8,
     
+    /// Set once by init handler
+    pub is_initialized: bool,
+    
+    /// Account layout version
+    pub version: u8,
+    
+    /// LP share fee tokens (PDOX) waiting to be swapped to SOL
+    pub accumulated_token_fees: u64,
+    
+    /// Token pool price at last growth (lp_value::PRICE_SCALE fixed-point; 0 = no baseline)
+    pub last_known_price: u64,
+    
+    /// Circuit breaker: max price drop since last growth, in bps (0 = disabled)
+    pub max_mint_ratio_bps: u16,
+    
+    /// Second LP pool on another venue (Pubkey::default() = single pool)
+    pub secondary_lp_pool: Pubkey,
+    
+    /// Share of growth SOL for lp_pool, in bps (0 with no secondary = legacy = 10000)
+    pub primary_pool_weight_bps: u16,
+    
+    /// Share of growth SOL for secondary_lp_pool, in bps
+    pub secondary_pool_weight_bps: u16,
+    
     /// Reserved for future use
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 8],
 }
 
 impl LpGrowthManager {
@@ -68,11 +114,25 @@ impl LpGrowthManager {
         8 +  // last_growth_time
         8 +  // total_sol_added
         8 +  // total_tokens_minted
-        8 +  // accumulated_fees
+        8 +  // accumulated_sol
         1 +  // is_locked
         64 + // lock_reason
         1 +  // bump
-        64;  // reserved
+        1 +  // is_initialized
+        1 +  // version
+        8 +  // accumulated_token_fees
+        8 +  // last_known_price
+        2 +  // max_mint_ratio_bps
+        32 + // secondary_lp_pool
+        2 +  // primary_pool_weight_bps
+        2 +  // secondary_pool_weight_bps
+        8;   // reserved
+    
+    /// Set up by its init handler. Accounts created before the flag existed
+    /// read it as 0, so a nonzero `mint` (always written at init) also counts
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized || self.mint != Pubkey::default()
+    }
     
     /// Check if cooldown has passed
     pub fn can_execute_growth(&self, current_time: i64) -> bool {
@@ -81,12 +141,115 @@ impl LpGrowthManager {
         }
         
         let time_since_last = current_time - self.last_growth_time;
-        time_since_last >= self.cooldown_seconds
+        time_since_last >= self.effective_cooldown_seconds()
+    }
+    
+    /// Cooldown with the minimum applied (accounts created before it was enforced may hold 0)
+    pub fn effective_cooldown_seconds(&self) -> i64 {
+        self.cooldown_seconds.max(MIN_GROWTH_COOLDOWN_SECONDS)
     }
     
     /// Check if enough fees accumulated
     pub fn has_enough_fees(&self) -> bool {
-        self.accumulated_fees >= self.min_fee_threshold
+        self.accumulated_sol >= self.min_fee_threshold
+    }
+    
+    /// Check if price fell more than max_mint_ratio_bps since last growth
+    /// Minting into a crash dilutes holders further, so growth pauses instead.
+    /// No baseline, unknown price (0) or a disabled breaker never trips.
+    pub fn price_dropped_too_far(&self, current_price: u64) -> bool {
+        if self.max_mint_ratio_bps == 0 || self.last_known_price == 0 || current_price == 0 {
+            return false;
+        }
+        
+        let min_price = (self.last_known_price as u128)
+            .saturating_mul(10_000u128.saturating_sub(self.max_mint_ratio_bps as u128))
+            / 10_000;
+        
+        (current_price as u128) < min_price
+    }
+    
+    /// Pool weights in effect as (primary, secondary) bps
+    /// Managers created before multi-pool support send everything to lp_pool
+    pub fn effective_pool_weights(&self) -> (u16, u16) {
+        if self.primary_pool_weight_bps == 0 && self.secondary_pool_weight_bps == 0 {
+            (10_000, 0)
+        } else {
+            (self.primary_pool_weight_bps, self.secondary_pool_weight_bps)
+        }
+    }
+    
+    /// Check a pool configuration: weights sum to 10000, every configured pool gets a share
+    pub fn validate_pools(
+        lp_pool: &Pubkey,
+        secondary_lp_pool: &Pubkey,
+        primary_weight_bps: u16,
+        secondary_weight_bps: u16,
+    ) -> Result<()> {
+        require!(
+            primary_weight_bps as u32 + secondary_weight_bps as u32 == 10_000,
+            crate::ParadoxError::InvalidPoolWeights
+        );
+        require!(primary_weight_bps > 0, crate::ParadoxError::InvalidPoolWeights);
+        
+        if *secondary_lp_pool == Pubkey::default() {
+            require!(secondary_weight_bps == 0, crate::ParadoxError::InvalidPoolWeights);
+        } else {
+            require!(secondary_weight_bps > 0, crate::ParadoxError::InvalidPoolWeights);
+            require!(secondary_lp_pool != lp_pool, crate::ParadoxError::InvalidPoolWeights);
+        }
+        
+        Ok(())
+    }
+    
+    /// Split `sol_amount` across the pools by weight as [(pool, lamports); MAX_LP_POOLS]
+    /// Rounding dust goes to lp_pool; an unset secondary gets 0
+    pub fn pool_allocations(&self, sol_amount: u64) -> [(Pubkey, u64); MAX_LP_POOLS] {
+        let (_, secondary_weight) = self.effective_pool_weights();
+        
+        let to_secondary = if self.secondary_lp_pool == Pubkey::default() {
+            0
+        } else {
+            ((sol_amount as u128).saturating_mul(secondary_weight as u128) / 10_000) as u64
+        };
+        
+        [
+            (self.lp_pool, sol_amount.saturating_sub(to_secondary)),
+            (self.secondary_lp_pool, to_secondary),
+        ]
+    }
+    
+    /// Lamports allocated to `pool` out of `sol_amount` (None = not one of our pools)
+    pub fn allocation_for(&self, pool: &Pubkey, sol_amount: u64) -> Option<u64> {
+        self.pool_allocations(sol_amount)
+            .iter()
+            .find(|(p, _)| *p != Pubkey::default() && p == pool)
+            .map(|(_, amount)| *amount)
+    }
+    
+    /// Book a completed growth: take the added SOL off accumulated SOL, bump lifetime counters
+    /// Shared by every DEX path (execute_lp_growth, execute_lp_growth_meteora)
+    pub fn record_growth(
+        &mut self,
+        sol_added: u64,
+        tokens_minted: u64,
+        current_price: u64,
+        current_time: i64,
+    ) -> Result<()> {
+        // Single-pool growth adds everything; a one-venue path may leave other pools' shares
+        self.accumulated_sol = self.accumulated_sol.saturating_sub(sol_added);
+        self.last_growth_time = current_time;
+        if current_price > 0 {
+            self.last_known_price = current_price;
+        }
+        self.total_sol_added = self.total_sol_added
+            .checked_add(sol_added)
+            .ok_or(crate::ParadoxError::MathOverflow)?;
+        self.total_tokens_minted = self.total_tokens_minted
+            .checked_add(tokens_minted)
+            .ok_or(crate::ParadoxError::MathOverflow)?;
+        
+        Ok(())
     }
     
     // =========================================================================
@@ -101,7 +264,7 @@ impl LpGrowthManager {
     // For Meteora: Use meteora DLMM SDK
     //
     // Basic formula:
-    //   sol_to_add = accumulated_fees
+    //   sol_to_add = accumulated_sol (fee tokens swapped via swap_fees_to_sol)
     //   tokens_to_mint = sol_to_add * current_price
     //   add_liquidity(sol_to_add, tokens_to_mint)
     //
@@ -142,3 +305,66 @@ impl LpGrowthManager {
     }
 }
 
+impl VersionedAccount for LpGrowthManager {
+    fn version(&self) -> u8 {
+        self.version
+    }
+    
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+    
+    fn owner_key(&self) -> Pubkey {
+        self.mint
+    }
+    
+    fn populate_new_fields(&mut self, old_version: u8) {
+        if old_version == 0 {
+            self.is_initialized = true;
+            let (primary, secondary) = self.effective_pool_weights();
+            self.primary_pool_weight_bps = primary;
+            self.secondary_pool_weight_bps = secondary;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const T0: i64 = 1_700_000_000;
+    
+    fn manager_with_cooldown(cooldown_seconds: i64) -> LpGrowthManager {
+        let mut manager = LpGrowthManager::try_deserialize_unchecked(
+            &mut &vec![0u8; LpGrowthManager::LEN][..]
+        ).unwrap();
+        manager.cooldown_seconds = cooldown_seconds;
+        manager.last_growth_time = T0;
+        manager
+    }
+    
+    #[test]
+    fn cooldown_boundary() {
+        let mut manager = manager_with_cooldown(3_600);
+        assert_eq!(manager.effective_cooldown_seconds(), 3_600);
+        
+        assert!(!manager.can_execute_growth(T0 + 3_600 - 1));
+        assert!(manager.can_execute_growth(T0 + 3_600));
+        
+        manager.is_locked = true;
+        assert!(!manager.can_execute_growth(T0 + 3_600));
+    }
+    
+    #[test]
+    fn legacy_zero_cooldown_is_clamped() {
+        let manager = manager_with_cooldown(0);
+        assert_eq!(manager.effective_cooldown_seconds(), MIN_GROWTH_COOLDOWN_SECONDS);
+        
+        // A second growth in the same timestamp is refused, the next second is fine
+        assert!(!manager.can_execute_growth(T0));
+        assert!(manager.can_execute_growth(T0 + MIN_GROWTH_COOLDOWN_SECONDS));
+        
+        // Negative legacy values clamp the same way
+        assert_eq!(manager_with_cooldown(-5).effective_cooldown_seconds(), MIN_GROWTH_COOLDOWN_SECONDS);
+    }
+}
@@ -17,6 +17,7 @@
  */
 
 use anchor_lang::prelude::*;
+use super::VersionedAccount;
 
 // =============================================================================
 // TIMELOCK CONSTANTS
@@ -42,6 +43,15 @@ pub const MAX_PENDING_WITHDRAWALS: usize = 3;
 /// Maximum snapshots stored
 pub const MAX_SNAPSHOTS: usize = 5;
 
+/// Cooldown before a new withdrawal can be announced after a cancel: 1 hour
+pub const ANNOUNCE_AFTER_CANCEL_COOLDOWN_SECONDS: i64 = 60 * 60;
+
+/// Maximum holders per HolderBalancesSnapshot page
+pub const MAX_HOLDERS_PER_PAGE: usize = 100;
+
+/// Default max snapshot age for restore: 90 days
+pub const DEFAULT_MAX_SNAPSHOT_AGE_SECONDS: u32 = 90 * 24 * 60 * 60;
+
 // =============================================================================
 // ENUMS
 // =============================================================================
@@ -79,6 +89,83 @@ impl Default for LpLockStatus {
     }
 }
 
+impl LpLockStatus {
+    /// Legal transition table (staying in an initialized state is always allowed)
+    /// 
+    /// NotInitialized    → Active
+    /// Active            → WithdrawalPending, Withdrawn, Restored
+    /// WithdrawalPending → Active, Withdrawn
+    /// Withdrawn         → Restored
+    /// Restored          → Active, WithdrawalPending, Withdrawn
+    pub fn can_transition_to(self, new: Self) -> bool {
+        use LpLockStatus::*;
+        
+        if self == new {
+            return self != NotInitialized;
+        }
+        
+        matches!(
+            (self, new),
+            (NotInitialized, Active)
+                | (Active, WithdrawalPending)
+                | (Active, Withdrawn)
+                | (Active, Restored)
+                | (WithdrawalPending, Active)
+                | (WithdrawalPending, Withdrawn)
+                | (Withdrawn, Restored)
+                | (Restored, Active)
+                | (Restored, WithdrawalPending)
+                | (Restored, Withdrawn)
+        )
+    }
+}
+
+/// Why a snapshot was taken (stored in the first byte of `LpSnapshot.reason`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotReason {
+    /// Taken by admin via take_lp_snapshot
+    Manual = 0,
+    /// Taken automatically when a withdrawal is announced
+    PreWithdrawal = 1,
+    /// Taken ahead of a restore / relaunch
+    Restore = 2,
+    /// Taken during an emergency
+    Emergency = 3,
+}
+
+impl SnapshotReason {
+    /// Bytes available for the free-form note after the type byte
+    pub const NOTE_LEN: usize = 31;
+    
+    pub fn from_byte(b: u8) -> Option<Self> {
+        match b {
+            0 => Some(Self::Manual),
+            1 => Some(Self::PreWithdrawal),
+            2 => Some(Self::Restore),
+            3 => Some(Self::Emergency),
+            _ => None,
+        }
+    }
+    
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Manual => "MANUAL",
+            Self::PreWithdrawal => "PRE_WITHDRAWAL",
+            Self::Restore => "RESTORE",
+            Self::Emergency => "EMERGENCY",
+        }
+    }
+    
+    /// Encode reason type + note into the 32-byte snapshot reason field
+    pub fn encode(self, note: &[u8]) -> [u8; 32] {
+        let mut reason = [0u8; 32];
+        reason[0] = self as u8;
+        let len = note.len().min(Self::NOTE_LEN);
+        reason[1..1 + len].copy_from_slice(&note[..len]);
+        reason
+    }
+}
+
 // =============================================================================
 // SNAPSHOT STRUCTURES
 // =============================================================================
@@ -99,7 +186,7 @@ pub struct LpSnapshot {
     pub id: u64,
     /// Timestamp when snapshot was taken
     pub timestamp: i64,
-    /// Reason for snapshot
+    /// Reason for snapshot (byte 0 = SnapshotReason, bytes 1..32 = note)
     pub reason: [u8; 32],
     /// LP token amount at snapshot
     pub lp_tokens: u64,
@@ -117,6 +204,20 @@ pub struct LpSnapshot {
     pub was_restored: bool,
 }
 
+impl LpSnapshot {
+    /// Snapshot reason type (None for unknown / legacy text reasons)
+    pub fn reason_kind(&self) -> Option<SnapshotReason> {
+        SnapshotReason::from_byte(self.reason[0])
+    }
+    
+    /// Free-form note after the type byte (trailing zeros trimmed)
+    pub fn reason_note(&self) -> &[u8] {
+        let note = &self.reason[1..];
+        let len = note.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        &note[..len]
+    }
+}
+
 /// Pending withdrawal request
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
 pub struct PendingWithdrawal {
@@ -136,6 +237,42 @@ pub struct PendingWithdrawal {
     pub is_active: bool,
 }
 
+impl PendingWithdrawal {
+    /// Nonce the executor must echo back - the announcement's own snapshot ID
+    /// Invariant: every announce takes a fresh snapshot (snapshot_counter only
+    /// grows), so a slot reused after cancel + re-announce gets a new nonce and
+    /// an execution built for the old announcement can never succeed
+    pub fn execution_nonce(&self) -> u64 {
+        self.snapshot_id
+    }
+}
+
+/// Machine-readable phase data (returned via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PhaseInfo {
+    /// Current phase (0 = Emergency, 1 = Stabilization, 2 = Permanent)
+    pub phase: u8,
+    /// Timelock required for withdrawals announced now
+    pub required_timelock_seconds: i64,
+    /// Days until next phase (None in Permanent)
+    pub days_until_next_phase: Option<i64>,
+}
+
+/// Active pending withdrawal with timings (returned via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct PendingWithdrawalView {
+    /// Slot index (pass to execute/cancel)
+    pub slot: u8,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub announced_at: i64,
+    pub execute_after: i64,
+    /// Seconds until executable (0 once the timelock has passed)
+    pub time_until_executable: i64,
+    /// Nonce the executor must echo back
+    pub execution_nonce: u64,
+}
+
 // =============================================================================
 // MAIN LP LOCK ACCOUNT
 // =============================================================================
@@ -215,8 +352,38 @@ pub struct LpLock {
     
     /// Bump seed for PDA
     pub bump: u8,
+    
+    // ─────────────────────────────────────────────────────────────────────────
+    // EXTENSIONS (carved from reserved - everything above is the original
+    // layout, so locks created before these fields read them as 0)
+    // ─────────────────────────────────────────────────────────────────────────
+    
+    /// Account layout version
+    pub version: u8,
+    /// Pinned withdrawal recipient (Pubkey::default() = any recipient allowed)
+    pub allowed_recipient: Pubkey,
+    /// Total withdrawals announced (lifetime)
+    pub announcements_count: u32,
+    /// Total withdrawals cancelled (lifetime)
+    pub cancellations_count: u32,
+    /// Timestamp of last cancellation (0 = never)
+    pub last_cancel_time: i64,
+    /// One-time emergency withdrawal used (15 min window after creation)
+    pub emergency_withdrawal_used: bool,
+    /// Share of initial LP that can never be withdrawn via timelock (0 = none)
+    pub permanent_floor_bps: u16,
+    /// Self-imposed cap on concurrent pending withdrawals (1..=3, 0 = legacy = 3)
+    pub max_pending_allowed: u8,
+    /// Bit i set = slot i was active at the last admin transfer (announced by a previous admin)
+    pub inherited_slots: u8,
+    /// Snapshots older than this can't be restored without governance (0 = default 90 days)
+    pub max_snapshot_age_seconds: u32,
+    /// Hours after execute_after before an unexecuted withdrawal expires (0 = never)
+    pub execution_grace_hours: u16,
+    /// Announcements are capped by the LpHolderGuard account (one-way opt-in)
+    pub holder_guard_enabled: bool,
     /// Reserved for future use
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 3],
 }
 
 impl LpLock {
@@ -240,20 +407,35 @@ impl LpLock {
         (8 + 32 + 8 + 8 + 64 + 8 + 1) * 3 + // pending_withdrawals (3x ~129 bytes)
         1 +  // pending_count
         1 +  // bump
-        64;  // reserved
+        1 +  // version
+        32 + // allowed_recipient
+        4 +  // announcements_count
+        4 +  // cancellations_count
+        8 +  // last_cancel_time
+        1 +  // emergency_withdrawal_used
+        2 +  // permanent_floor_bps
+        1 +  // max_pending_allowed
+        1 +  // inherited_slots
+        4 +  // max_snapshot_age_seconds
+        2 +  // execution_grace_hours
+        1 +  // holder_guard_enabled
+        3;   // reserved
     
     // =========================================================================
     // PHASE CALCULATION
     // =========================================================================
     
-    /// Get current phase based on time since creation
-    pub fn get_current_phase(&self) -> LpLockPhase {
-        let now = match Clock::get() {
-            Ok(clock) => clock.unix_timestamp,
-            Err(_) => return self.phase,
-        };
-        
-        let age = now - self.created_at;
+    /// Check if lock has been initialized (status is set by initialize)
+    pub fn is_initialized(&self) -> bool {
+        self.status != LpLockStatus::NotInitialized
+    }
+    
+    // Pure `*_at(current_time)` variants hold the logic so it can be exercised
+    // without a validator; the clock-reading wrappers are for handlers.
+    
+    /// Get phase at `current_time` based on time since creation
+    pub fn get_phase_at(&self, current_time: i64) -> LpLockPhase {
+        let age = current_time - self.created_at;
         
         if age < PHASE1_DURATION_SECONDS {
             LpLockPhase::Emergency
@@ -264,9 +446,26 @@ impl LpLock {
         }
     }
     
+    /// Get current phase based on time since creation
+    pub fn get_current_phase(&self) -> LpLockPhase {
+        match Clock::get() {
+            Ok(clock) => self.get_phase_at(clock.unix_timestamp),
+            Err(_) => self.phase,
+        }
+    }
+    
+    /// Get required timelock for the phase at `current_time`
+    pub fn get_required_timelock_at(&self, current_time: i64) -> i64 {
+        Self::timelock_for_phase(self.get_phase_at(current_time))
+    }
+    
     /// Get required timelock for current phase
     pub fn get_required_timelock(&self) -> i64 {
-        match self.get_current_phase() {
+        Self::timelock_for_phase(self.get_current_phase())
+    }
+    
+    fn timelock_for_phase(phase: LpLockPhase) -> i64 {
+        match phase {
             LpLockPhase::Emergency => PHASE1_TIMELOCK_SECONDS,
             LpLockPhase::Stabilization => PHASE2_TIMELOCK_SECONDS,
             LpLockPhase::Permanent => PHASE3_TIMELOCK_SECONDS,
@@ -282,22 +481,53 @@ impl LpLock {
         }
     }
     
-    /// Get days until next phase
-    pub fn days_until_next_phase(&self) -> Option<i64> {
-        let now = match Clock::get() {
-            Ok(clock) => clock.unix_timestamp,
-            Err(_) => return None,
-        };
-        
-        let age = now - self.created_at;
+    /// Get days until next phase at `current_time`
+    pub fn days_until_next_phase_at(&self, current_time: i64) -> Option<i64> {
+        let age = current_time - self.created_at;
         
-        match self.get_current_phase() {
+        match self.get_phase_at(current_time) {
             LpLockPhase::Emergency => Some((PHASE1_DURATION_SECONDS - age) / (24 * 60 * 60)),
             LpLockPhase::Stabilization => Some((PHASE2_DURATION_SECONDS - age) / (24 * 60 * 60)),
             LpLockPhase::Permanent => None, // No next phase
         }
     }
     
+    /// Get days until next phase
+    pub fn days_until_next_phase(&self) -> Option<i64> {
+        Clock::get().ok().and_then(|clock| self.days_until_next_phase_at(clock.unix_timestamp))
+    }
+    
+    /// Sync stored phase with the time-based phase
+    /// Returns (old, new) if it changed, None if already current
+    pub fn advance_phase(&mut self, current_time: i64) -> Option<(LpLockPhase, LpLockPhase)> {
+        let current = self.get_phase_at(current_time);
+        if current == self.phase {
+            return None;
+        }
+        
+        let old = self.phase;
+        self.phase = current;
+        Some((old, current))
+    }
+    
+    /// Get phase data at `current_time`
+    pub fn get_phase_info_at(&self, current_time: i64) -> PhaseInfo {
+        PhaseInfo {
+            phase: self.get_phase_at(current_time) as u8,
+            required_timelock_seconds: self.get_required_timelock_at(current_time),
+            days_until_next_phase: self.days_until_next_phase_at(current_time),
+        }
+    }
+    
+    /// Get phase data for lightweight clients
+    pub fn get_phase_info(&self) -> PhaseInfo {
+        PhaseInfo {
+            phase: self.get_current_phase() as u8,
+            required_timelock_seconds: self.get_required_timelock(),
+            days_until_next_phase: self.days_until_next_phase(),
+        }
+    }
+    
     // =========================================================================
     // INITIALIZATION
     // =========================================================================
@@ -312,10 +542,12 @@ impl LpLock {
         admin: Pubkey,
         emergency_multisig: Pubkey,
         lp_amount: u64,
+        allowed_recipient: Pubkey,
+        permanent_floor_bps: u16,
+        max_pending_allowed: u8,
         bump: u8,
-    ) {
-        let clock = Clock::get().expect("Clock required");
-        
+        current_time: i64,
+    ) -> Result<()> {
         self.mint = mint;
         self.lp_pool = lp_pool;
         self.lp_token_mint = lp_token_mint;
@@ -323,16 +555,27 @@ impl LpLock {
         self.admin = admin;
         self.governance = admin;
         self.emergency_multisig = emergency_multisig;
-        self.created_at = clock.unix_timestamp;
+        self.created_at = current_time;
         self.phase = LpLockPhase::Emergency;
-        self.status = LpLockStatus::Active;
         self.lp_tokens_locked = lp_amount;
         self.total_withdrawn = 0;
         self.initial_lp_tokens = lp_amount;
         self.snapshot_counter = 0;
         self.latest_restorable_snapshot = 0;
+        self.max_snapshot_age_seconds = DEFAULT_MAX_SNAPSHOT_AGE_SECONDS;
         self.pending_count = 0;
+        self.max_pending_allowed = max_pending_allowed;
+        self.execution_grace_hours = 0;
+        self.allowed_recipient = allowed_recipient;
+        self.announcements_count = 0;
+        self.cancellations_count = 0;
+        self.last_cancel_time = 0;
+        self.inherited_slots = 0;
+        self.emergency_withdrawal_used = false;
+        self.permanent_floor_bps = permanent_floor_bps;
         self.bump = bump;
+        self.version = crate::CURRENT_ACCOUNT_VERSION;
+        self.reserved = [0; 3];
         
         // Clear arrays
         for s in &mut self.snapshots {
@@ -341,6 +584,33 @@ impl LpLock {
         for pw in &mut self.pending_withdrawals {
             *pw = PendingWithdrawal::default();
         }
+        
+        self.set_status(LpLockStatus::Active)
+    }
+    
+    // =========================================================================
+    // STATUS
+    // =========================================================================
+    
+    /// Move to `new` status - the only place status changes
+    /// Rejects transitions outside the table in LpLockStatus::can_transition_to
+    /// and statuses that contradict the pending slots / locked amount
+    pub fn set_status(&mut self, new: LpLockStatus) -> Result<()> {
+        require!(
+            self.status.can_transition_to(new),
+            crate::ParadoxError::InvalidStatusTransition
+        );
+        
+        let consistent = match new {
+            LpLockStatus::WithdrawalPending => self.pending_count > 0,
+            LpLockStatus::Withdrawn => self.pending_count == 0 && self.lp_tokens_locked == 0,
+            LpLockStatus::Active | LpLockStatus::Restored => self.pending_count == 0,
+            LpLockStatus::NotInitialized => false,
+        };
+        require!(consistent, crate::ParadoxError::InvalidStatusTransition);
+        
+        self.status = new;
+        Ok(())
     }
     
     // =========================================================================
@@ -350,24 +620,24 @@ impl LpLock {
     /// Take a snapshot of current state
     pub fn take_snapshot(
         &mut self,
-        reason: [u8; 32],
+        reason: SnapshotReason,
+        note: &[u8],
         sol_reserve: u64,
         token_reserve: u64,
         total_supply: u64,
         holder_count: u32,
+        current_time: i64,
     ) -> u64 {
-        let clock = Clock::get().expect("Clock required");
-        
         self.snapshot_counter += 1;
         let snapshot_id = self.snapshot_counter;
         
-        // Rotate snapshots (keep last 5)
-        let idx = ((snapshot_id - 1) % 5) as usize;
+        // Evict oldest non-restorable snapshot (protects relaunch capability)
+        let idx = self.snapshot_slot_to_evict();
         
         self.snapshots[idx] = LpSnapshot {
             id: snapshot_id,
-            timestamp: clock.unix_timestamp,
-            reason,
+            timestamp: current_time,
+            reason: reason.encode(note),
             lp_tokens: self.lp_tokens_locked,
             sol_reserve,
             token_reserve,
@@ -382,6 +652,37 @@ impl LpLock {
         snapshot_id
     }
     
+    /// Pick snapshot slot to overwrite
+    /// Order: empty slot, oldest non-restorable, oldest restorable.
+    /// Never evicts latest_restorable_snapshot unless all five are restorable.
+    fn snapshot_slot_to_evict(&self) -> usize {
+        if let Some(idx) = self.snapshots.iter().position(|s| s.id == 0) {
+            return idx;
+        }
+        
+        let oldest = |restorable: bool| {
+            self.snapshots
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| s.id != self.latest_restorable_snapshot)
+                .filter(|(_, s)| (s.is_valid && !s.was_restored) == restorable)
+                .min_by_key(|(_, s)| s.id)
+                .map(|(i, _)| i)
+        };
+        
+        oldest(false)
+            .or_else(|| oldest(true))
+            .unwrap_or_else(|| {
+                // All five restorable: fall back to plain oldest
+                self.snapshots
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, s)| s.id)
+                    .map(|(i, _)| i)
+                    .unwrap_or(0)
+            })
+    }
+    
     /// Get snapshot by ID
     pub fn get_snapshot(&self, id: u64) -> Option<&LpSnapshot> {
         for s in &self.snapshots {
@@ -392,6 +693,54 @@ impl LpLock {
         None
     }
     
+    /// Max snapshot age in effect (0 = default)
+    pub fn effective_max_snapshot_age(&self) -> i64 {
+        match self.max_snapshot_age_seconds {
+            0 => DEFAULT_MAX_SNAPSHOT_AGE_SECONDS as i64,
+            n => n as i64,
+        }
+    }
+    
+    /// Check if a snapshot is too old to anchor a restore at `current_time`
+    pub fn is_snapshot_stale(&self, snapshot: &LpSnapshot, current_time: i64) -> bool {
+        current_time.saturating_sub(snapshot.timestamp) > self.effective_max_snapshot_age()
+    }
+    
+    /// Check if any stored snapshot can still be used for restore
+    pub fn has_restorable_snapshot(&self) -> bool {
+        self.snapshots.iter().any(|s| s.id != 0 && s.is_valid && !s.was_restored)
+    }
+    
+    /// Invalidate snapshot and repoint latest_restorable_snapshot if needed
+    pub fn invalidate_snapshot(&mut self, id: u64) -> Result<()> {
+        let snapshot = self.snapshots
+            .iter_mut()
+            .find(|s| s.id == id && s.id != 0 && s.is_valid)
+            .ok_or(error!(crate::ParadoxError::SnapshotNotFound))?;
+        
+        snapshot.is_valid = false;
+        
+        if self.latest_restorable_snapshot == id {
+            self.latest_restorable_snapshot = self.snapshots
+                .iter()
+                .filter(|s| s.id != 0 && s.is_valid && !s.was_restored)
+                .map(|s| s.id)
+                .max()
+                .unwrap_or(0);
+        }
+        
+        Ok(())
+    }
+    
+    /// Holder count of the most recent valid snapshot (0 = none)
+    pub fn latest_valid_holder_count(&self) -> u32 {
+        self.snapshots
+            .iter()
+            .filter(|s| s.id != 0 && s.is_valid)
+            .max_by_key(|s| s.id)
+            .map_or(0, |s| s.holder_count)
+    }
+    
     /// Mark snapshot as restored
     pub fn mark_snapshot_restored(&mut self, id: u64) {
         for s in &mut self.snapshots {
@@ -405,6 +754,11 @@ impl LpLock {
     // WITHDRAWAL MANAGEMENT
     // =========================================================================
     
+    /// Check if recipient is allowed (always true when no recipient is pinned)
+    pub fn is_recipient_allowed(&self, recipient: &Pubkey) -> bool {
+        self.allowed_recipient == Pubkey::default() || self.allowed_recipient == *recipient
+    }
+    
     /// Announce a new withdrawal (starts timelock)
     pub fn announce_withdrawal(
         &mut self,
@@ -412,8 +766,33 @@ impl LpLock {
         recipient: Pubkey,
         reason: [u8; 64],
         snapshot_id: u64,
+        current_time: i64,
     ) -> Result<usize> {
-        let clock = Clock::get()?;
+        require!(
+            self.is_recipient_allowed(&recipient),
+            crate::ParadoxError::RecipientNotAllowed
+        );
+        
+        // Rate-limit announce/cancel spam
+        require!(
+            self.last_cancel_time == 0
+                || current_time >= self.last_cancel_time + ANNOUNCE_AFTER_CANCEL_COOLDOWN_SECONDS,
+            crate::ParadoxError::AnnouncementCooldownActive
+        );
+        
+        // Other active slots already commit part of the locked LP
+        require!(
+            amount <= self.uncommitted_lp_tokens(),
+            crate::ParadoxError::InsufficientLpTokens
+        );
+        
+        require!(self.respects_floor(amount), crate::ParadoxError::LiquidityFloorBreached);
+        
+        // Self-imposed cap (the array size is the physical cap below)
+        require!(
+            (self.pending_count as usize) < self.effective_max_pending(),
+            crate::ParadoxError::TooManyPendingWithdrawals
+        );
         
         // Find empty slot
         let slot = self.pending_withdrawals
@@ -421,43 +800,107 @@ impl LpLock {
             .position(|pw| !pw.is_active)
             .ok_or(error!(crate::ParadoxError::TooManyPendingWithdrawals))?;
         
-        let timelock = self.get_required_timelock();
+        let timelock = self.get_required_timelock_at(current_time);
+        
+        self.announcements_count = self.announcements_count.saturating_add(1);
         
+        // snapshot_id doubles as the execution nonce (see PendingWithdrawal::execution_nonce)
         self.pending_withdrawals[slot] = PendingWithdrawal {
             amount,
             recipient,
-            announced_at: clock.unix_timestamp,
-            execute_after: clock.unix_timestamp + timelock,
+            announced_at: current_time,
+            execute_after: current_time + timelock,
             reason,
             snapshot_id,
             is_active: true,
         };
+        self.inherited_slots &= !(1u8 << slot);
         
         self.pending_count += 1;
-        self.status = LpLockStatus::WithdrawalPending;
+        self.set_status(LpLockStatus::WithdrawalPending)?;
         
         Ok(slot)
     }
     
-    /// Check if withdrawal can be executed
-    pub fn can_execute_withdrawal(&self, slot: usize) -> bool {
+    /// Concurrent pending withdrawals allowed (locks created before the cap existed get the array size)
+    pub fn effective_max_pending(&self) -> usize {
+        match self.max_pending_allowed {
+            0 => MAX_PENDING_WITHDRAWALS,
+            n => (n as usize).min(MAX_PENDING_WITHDRAWALS),
+        }
+    }
+    
+    /// Sum of amounts in active pending withdrawals
+    pub fn pending_total(&self) -> u64 {
+        self.pending_withdrawals
+            .iter()
+            .filter(|pw| pw.is_active)
+            .fold(0u64, |acc, pw| acc.saturating_add(pw.amount))
+    }
+    
+    /// Locked LP not yet committed to a pending withdrawal
+    pub fn uncommitted_lp_tokens(&self) -> u64 {
+        self.lp_tokens_locked.saturating_sub(self.pending_total())
+    }
+    
+    /// LP tokens that must always remain locked
+    /// Uses u128 intermediate calculations to prevent overflow
+    pub fn liquidity_floor(&self) -> u64 {
+        ((self.initial_lp_tokens as u128)
+            .saturating_mul(self.permanent_floor_bps as u128)
+            / 10_000) as u64
+    }
+    
+    /// Check that withdrawing `amount` keeps lp_tokens_locked at or above the floor
+    pub fn respects_floor(&self, amount: u64) -> bool {
+        self.lp_tokens_locked
+            .checked_sub(amount)
+            .map_or(false, |remaining| remaining >= self.liquidity_floor())
+    }
+    
+    /// Execution grace window in seconds (None = withdrawals never expire)
+    pub fn execution_grace_seconds(&self) -> Option<i64> {
+        match self.execution_grace_hours {
+            0 => None,
+            h => Some(h as i64 * 3600),
+        }
+    }
+    
+    /// Check if an active withdrawal went unexecuted past its grace window
+    pub fn is_withdrawal_expired_at(&self, slot: usize, current_time: i64) -> bool {
         if slot >= MAX_PENDING_WITHDRAWALS {
             return false;
         }
         
         let pw = &self.pending_withdrawals[slot];
-        if !pw.is_active {
+        match self.execution_grace_seconds() {
+            Some(grace) => pw.is_active && current_time > pw.execute_after.saturating_add(grace),
+            None => false,
+        }
+    }
+    
+    /// Check if withdrawal can be executed at `current_time`
+    pub fn can_execute_withdrawal_at(&self, slot: usize, current_time: i64) -> bool {
+        if slot >= MAX_PENDING_WITHDRAWALS {
             return false;
         }
         
+        let pw = &self.pending_withdrawals[slot];
+        pw.is_active
+            && current_time >= pw.execute_after
+            && !self.is_withdrawal_expired_at(slot, current_time)
+    }
+    
+    /// Check if withdrawal can be executed
+    pub fn can_execute_withdrawal(&self, slot: usize) -> bool {
         match Clock::get() {
-            Ok(clock) => clock.unix_timestamp >= pw.execute_after,
+            Ok(clock) => self.can_execute_withdrawal_at(slot, clock.unix_timestamp),
             Err(_) => false,
         }
     }
     
-    /// Get time remaining until withdrawal executable
-    pub fn time_until_executable(&self, slot: usize) -> i64 {
+    /// Get time remaining at `current_time` until withdrawal executable
+    pub fn time_until_executable_at(&self, slot: usize, current_time: i64) -> i64 {
         if slot >= MAX_PENDING_WITHDRAWALS {
             return i64::MAX;
         }
@@ -467,22 +910,65 @@ impl LpLock {
             return i64::MAX;
         }
         
+        (pw.execute_after - current_time).max(0)
+    }
+    
+    /// Get time remaining until withdrawal executable
+    pub fn time_until_executable(&self, slot: usize) -> i64 {
         match Clock::get() {
-            Ok(clock) => (pw.execute_after - clock.unix_timestamp).max(0),
+            Ok(clock) => self.time_until_executable_at(slot, clock.unix_timestamp),
             Err(_) => i64::MAX,
         }
     }
     
+    /// Active pending withdrawals with timings at `current_time`
+    pub fn pending_withdrawal_views_at(&self, current_time: i64) -> Vec<PendingWithdrawalView> {
+        self.pending_withdrawals
+            .iter()
+            .enumerate()
+            .filter(|(_, pw)| pw.is_active)
+            .map(|(i, pw)| PendingWithdrawalView {
+                slot: i as u8,
+                amount: pw.amount,
+                recipient: pw.recipient,
+                announced_at: pw.announced_at,
+                execute_after: pw.execute_after,
+                time_until_executable: self.time_until_executable_at(i, current_time),
+                execution_nonce: pw.execution_nonce(),
+            })
+            .collect()
+    }
+    
     /// Execute withdrawal
-    pub fn execute_withdrawal(&mut self, slot: usize) -> Result<(u64, Pubkey)> {
+    /// `nonce` must match the announcement's execution_nonce
+    pub fn execute_withdrawal(
+        &mut self,
+        slot: usize,
+        nonce: u64,
+        current_time: i64,
+    ) -> Result<(u64, Pubkey)> {
         require!(slot < MAX_PENDING_WITHDRAWALS, crate::ParadoxError::InvalidWithdrawalSlot);
         require!(self.pending_withdrawals[slot].is_active, crate::ParadoxError::NoActiveWithdrawal);
-        require!(self.can_execute_withdrawal(slot), crate::ParadoxError::TimelockNotExpired);
+        require!(
+            self.pending_withdrawals[slot].execution_nonce() == nonce,
+            crate::ParadoxError::StaleWithdrawalNonce
+        );
+        require!(
+            !self.is_withdrawal_expired_at(slot, current_time),
+            crate::ParadoxError::WithdrawalExpired
+        );
+        require!(
+            self.can_execute_withdrawal_at(slot, current_time),
+            crate::ParadoxError::TimelockNotExpired
+        );
         
         let pw = &self.pending_withdrawals[slot];
         let amount = pw.amount;
         let recipient = pw.recipient;
         
+        // Re-check floor (other withdrawals may have executed since announcement)
+        require!(self.respects_floor(amount), crate::ParadoxError::LiquidityFloorBreached);
+        
         // Update state
         self.lp_tokens_locked = self.lp_tokens_locked.saturating_sub(amount);
         self.total_withdrawn = self.total_withdrawn.saturating_add(amount);
@@ -493,26 +979,106 @@ impl LpLock {
         
         // Update status
         if self.pending_count == 0 {
-            self.status = if self.lp_tokens_locked == 0 {
+            self.set_status(if self.lp_tokens_locked == 0 {
                 LpLockStatus::Withdrawn
             } else {
                 LpLockStatus::Active
-            };
+            })?;
         }
         
         Ok((amount, recipient))
     }
     
+    /// Check if an active slot was announced before the last admin transfer
+    pub fn is_inherited_withdrawal(&self, slot: usize) -> bool {
+        slot < MAX_PENDING_WITHDRAWALS
+            && self.pending_withdrawals[slot].is_active
+            && self.inherited_slots & (1u8 << slot) != 0
+    }
+    
+    /// Flag every active slot as announced by the outgoing admin
+    pub fn mark_inherited_withdrawals(&mut self) {
+        self.inherited_slots = self.pending_withdrawals
+            .iter()
+            .enumerate()
+            .filter(|(_, pw)| pw.is_active)
+            .fold(0u8, |mask, (i, _)| mask | (1u8 << i));
+    }
+    
     /// Cancel withdrawal
-    pub fn cancel_withdrawal(&mut self, slot: usize) -> Result<()> {
+    pub fn cancel_withdrawal(&mut self, slot: usize, current_time: i64) -> Result<()> {
+        require!(slot < MAX_PENDING_WITHDRAWALS, crate::ParadoxError::InvalidWithdrawalSlot);
+        require!(self.pending_withdrawals[slot].is_active, crate::ParadoxError::NoActiveWithdrawal);
+        
+        self.pending_withdrawals[slot] = PendingWithdrawal::default();
+        self.pending_count = self.pending_count.saturating_sub(1);
+        self.cancellations_count = self.cancellations_count.saturating_add(1);
+        self.last_cancel_time = current_time;
+        
+        if self.pending_count == 0 {
+            self.set_status(LpLockStatus::Active)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Clear a withdrawal that went unexecuted past its grace window
+    /// Not counted as a cancellation - nobody chose to abandon it
+    pub fn expire_withdrawal(&mut self, slot: usize, current_time: i64) -> Result<()> {
         require!(slot < MAX_PENDING_WITHDRAWALS, crate::ParadoxError::InvalidWithdrawalSlot);
         require!(self.pending_withdrawals[slot].is_active, crate::ParadoxError::NoActiveWithdrawal);
+        require!(
+            self.is_withdrawal_expired_at(slot, current_time),
+            crate::ParadoxError::WithdrawalNotExpired
+        );
         
         self.pending_withdrawals[slot] = PendingWithdrawal::default();
         self.pending_count = self.pending_count.saturating_sub(1);
         
         if self.pending_count == 0 {
-            self.status = LpLockStatus::Active;
+            self.set_status(LpLockStatus::Active)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Check if lock is fully withdrawn and can be closed
+    pub fn can_close(&self) -> bool {
+        self.status == LpLockStatus::Withdrawn
+            && self.lp_tokens_locked == 0
+            && self.pending_count == 0
+    }
+    
+    // =========================================================================
+    // EMERGENCY WITHDRAWAL
+    // =========================================================================
+    
+    /// Check if the 15 min emergency window after creation is still open
+    pub fn is_emergency_window_open(&self, current_time: i64) -> bool {
+        current_time.saturating_sub(self.created_at) <= crate::LP_EMERGENCY_WINDOW_SECONDS
+    }
+    
+    /// One-time emergency withdrawal (no timelock, botched launch fix)
+    /// Not subject to permanent_floor_bps - the window closes before trading matters
+    /// Can't dip into LP already committed to announced withdrawals
+    pub fn emergency_withdraw(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        require!(!self.emergency_withdrawal_used, crate::ParadoxError::EmergencyAlreadyUsed);
+        require!(
+            self.is_emergency_window_open(current_time),
+            crate::ParadoxError::EmergencyWindowClosed
+        );
+        require!(amount <= self.uncommitted_lp_tokens(), crate::ParadoxError::InsufficientLpTokens);
+        
+        self.lp_tokens_locked = self.lp_tokens_locked
+            .checked_sub(amount)
+            .ok_or(crate::ParadoxError::MathOverflow)?;
+        self.total_withdrawn = self.total_withdrawn
+            .checked_add(amount)
+            .ok_or(crate::ParadoxError::MathOverflow)?;
+        self.emergency_withdrawal_used = true;
+        
+        if self.lp_tokens_locked == 0 && self.pending_count == 0 {
+            self.set_status(LpLockStatus::Withdrawn)?;
         }
         
         Ok(())
@@ -523,12 +1089,29 @@ impl LpLock {
     // =========================================================================
     
     /// Restore LP from snapshot (for relaunch)
-    pub fn restore_from_snapshot(&mut self, lp_amount: u64) {
+    /// Not allowed with withdrawals pending - they'd execute against the restored amount
+    pub fn restore_from_snapshot(&mut self, lp_amount: u64, current_time: i64) -> Result<()> {
         self.lp_tokens_locked = lp_amount;
-        self.status = LpLockStatus::Restored;
+        self.set_status(LpLockStatus::Restored)?;
         
         // Update phase to current (may have advanced during restore)
-        self.phase = self.get_current_phase();
+        self.phase = self.get_phase_at(current_time);
+        
+        Ok(())
+    }
+}
+
+impl VersionedAccount for LpLock {
+    fn version(&self) -> u8 {
+        self.version
+    }
+    
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+    
+    fn owner_key(&self) -> Pubkey {
+        self.mint
     }
 }
 
@@ -571,3 +1154,214 @@ impl HolderBalancesSnapshot {
         Self::BASE_LEN + (n * (32 + 8)) // wallet + balance per holder
     }
 }
+
+// =============================================================================
+// HOLDER GUARD (separate PDA, see LpLock.holder_guard_enabled)
+// =============================================================================
+
+/// Caps each announcement while the pool has many holders
+/// Above holder_guard_threshold holders, a single withdrawal can take at most
+/// holder_guard_max_bps of the locked LP, whatever the phase
+#[account]
+pub struct LpHolderGuard {
+    /// LP Lock this guard belongs to
+    pub lp_lock: Pubkey,
+    /// Guard applies when the holder count exceeds this (0 = disabled)
+    pub holder_guard_threshold: u32,
+    /// Max share of lp_tokens_locked per announcement while the guard applies
+    pub holder_guard_max_bps: u16,
+    /// Bump seed
+    pub bump: u8,
+    /// Account layout version
+    pub version: u8,
+    /// Reserved for future use
+    pub reserved: [u8; 16],
+}
+
+impl LpHolderGuard {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // lp_lock
+        4 +  // holder_guard_threshold
+        2 +  // holder_guard_max_bps
+        1 +  // bump
+        1 +  // version
+        16;  // reserved
+    
+    /// Max LP a single announcement may take with `holder_count` holders (None = no cap)
+    /// Uses u128 intermediate calculations to prevent overflow
+    pub fn max_withdrawal(&self, lp_tokens_locked: u64, holder_count: u32) -> Option<u64> {
+        if self.holder_guard_threshold == 0 || holder_count <= self.holder_guard_threshold {
+            return None;
+        }
+        
+        Some(((lp_tokens_locked as u128)
+            .saturating_mul(self.holder_guard_max_bps as u128)
+            / 10_000) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParadoxError;
+    
+    const T0: i64 = 1_700_000_000;
+    
+    /// Zeroed account data run through initialize, like create_pool_and_lock does
+    fn new_lock(lp_amount: u64) -> LpLock {
+        let mut lock = LpLock::try_deserialize_unchecked(&mut &vec![0u8; LpLock::LEN][..]).unwrap();
+        lock.initialize(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            lp_amount,
+            Pubkey::default(),
+            0,
+            0,
+            255,
+            T0,
+        ).unwrap();
+        lock
+    }
+    
+    /// Announce with its automatic snapshot, as announce_withdrawal_handler does
+    fn announce(lock: &mut LpLock, amount: u64, now: i64) -> usize {
+        let snapshot_id = lock.take_snapshot(SnapshotReason::PreWithdrawal, &[], 1, 1, 1, 1, now);
+        lock.announce_withdrawal(amount, Pubkey::new_unique(), [0u8; 64], snapshot_id, now).unwrap()
+    }
+    
+    #[test]
+    fn layout_matches_original_account_size() {
+        // Extensions live in the original reserved bytes - existing locks must still load
+        assert_eq!(LpLock::LEN, 1165);
+        assert_eq!(new_lock(1_000).try_to_vec().unwrap().len() + 8, LpLock::LEN);
+    }
+    
+    #[test]
+    fn reannounced_slot_rejects_stale_nonce() {
+        let mut lock = new_lock(1_000);
+        
+        let slot = announce(&mut lock, 100, T0);
+        let stale_nonce = lock.pending_withdrawals[slot].execution_nonce();
+        
+        // Cancel, then re-announce into the same slot once the cooldown is over
+        lock.cancel_withdrawal(slot, T0 + 1).unwrap();
+        let reannounce_time = T0 + 1 + ANNOUNCE_AFTER_CANCEL_COOLDOWN_SECONDS;
+        assert_eq!(announce(&mut lock, 100, reannounce_time), slot);
+        
+        let fresh_nonce = lock.pending_withdrawals[slot].execution_nonce();
+        assert_ne!(fresh_nonce, stale_nonce);
+        
+        // An execution built for the cancelled announcement is rejected...
+        let executable_at = lock.pending_withdrawals[slot].execute_after;
+        assert_eq!(
+            lock.execute_withdrawal(slot, stale_nonce, executable_at).unwrap_err(),
+            ParadoxError::StaleWithdrawalNonce.into()
+        );
+        
+        // ...and leaves the live one intact
+        assert_eq!(lock.execute_withdrawal(slot, fresh_nonce, executable_at).unwrap().0, 100);
+        assert_eq!(lock.lp_tokens_locked, 900);
+    }
+    
+    #[test]
+    fn illegal_status_transitions_are_rejected() {
+        use LpLockStatus::*;
+        
+        assert!(!NotInitialized.can_transition_to(Withdrawn));
+        assert!(!WithdrawalPending.can_transition_to(Restored));
+        assert!(!Withdrawn.can_transition_to(Active));
+        
+        // Same edges through set_status, with state that would otherwise be consistent
+        let mut lock = LpLock::try_deserialize_unchecked(&mut &vec![0u8; LpLock::LEN][..]).unwrap();
+        assert_eq!(
+            lock.set_status(Withdrawn).unwrap_err(),
+            ParadoxError::InvalidStatusTransition.into()
+        );
+        
+        let mut lock = new_lock(1_000);
+        announce(&mut lock, 100, T0);
+        assert!(lock.status == WithdrawalPending);
+        lock.pending_count = 0;
+        assert_eq!(
+            lock.set_status(Restored).unwrap_err(),
+            ParadoxError::InvalidStatusTransition.into()
+        );
+        
+        let mut lock = new_lock(0);
+        lock.set_status(Withdrawn).unwrap();
+        assert_eq!(
+            lock.set_status(Active).unwrap_err(),
+            ParadoxError::InvalidStatusTransition.into()
+        );
+    }
+    
+    #[test]
+    fn withdrawal_pending_requires_a_pending_withdrawal() {
+        let mut lock = new_lock(1_000);
+        assert!(lock.status == LpLockStatus::Active);
+        assert_eq!(lock.pending_count, 0);
+        
+        // Allowed edge, inconsistent state
+        assert!(LpLockStatus::Active.can_transition_to(LpLockStatus::WithdrawalPending));
+        assert_eq!(
+            lock.set_status(LpLockStatus::WithdrawalPending).unwrap_err(),
+            ParadoxError::InvalidStatusTransition.into()
+        );
+        assert!(lock.status == LpLockStatus::Active);
+    }
+    
+    #[test]
+    fn phase_and_timelock_follow_lock_age() {
+        let lock = new_lock(1_000);
+        
+        assert!(lock.get_phase_at(T0) == LpLockPhase::Emergency);
+        assert!(lock.get_phase_at(T0 + PHASE1_DURATION_SECONDS - 1) == LpLockPhase::Emergency);
+        assert!(lock.get_phase_at(T0 + PHASE1_DURATION_SECONDS) == LpLockPhase::Stabilization);
+        assert!(lock.get_phase_at(T0 + PHASE2_DURATION_SECONDS - 1) == LpLockPhase::Stabilization);
+        assert!(lock.get_phase_at(T0 + PHASE2_DURATION_SECONDS) == LpLockPhase::Permanent);
+        
+        assert_eq!(lock.get_required_timelock_at(T0), PHASE1_TIMELOCK_SECONDS);
+        assert_eq!(lock.get_required_timelock_at(T0 + PHASE1_DURATION_SECONDS), PHASE2_TIMELOCK_SECONDS);
+        assert_eq!(lock.get_required_timelock_at(T0 + PHASE2_DURATION_SECONDS), PHASE3_TIMELOCK_SECONDS);
+        
+        let day = 24 * 60 * 60;
+        assert_eq!(lock.days_until_next_phase_at(T0), Some(3));
+        assert_eq!(lock.days_until_next_phase_at(T0 + PHASE1_DURATION_SECONDS), Some(12));
+        assert_eq!(lock.days_until_next_phase_at(T0 + PHASE2_DURATION_SECONDS - day), Some(1));
+        assert_eq!(lock.days_until_next_phase_at(T0 + PHASE2_DURATION_SECONDS), None);
+    }
+    
+    #[test]
+    fn withdrawal_executes_only_inside_its_window() {
+        let mut lock = new_lock(1_000);
+        lock.execution_grace_hours = 24;
+        
+        let slot = announce(&mut lock, 100, T0);
+        let execute_after = T0 + PHASE1_TIMELOCK_SECONDS;
+        assert_eq!(lock.pending_withdrawals[slot].execute_after, execute_after);
+        
+        // Before the timelock
+        assert!(!lock.can_execute_withdrawal_at(slot, execute_after - 1));
+        assert_eq!(lock.time_until_executable_at(slot, T0), PHASE1_TIMELOCK_SECONDS);
+        assert_eq!(lock.time_until_executable_at(slot, execute_after - 1), 1);
+        
+        // Timelock over, through the end of the grace window
+        assert!(lock.can_execute_withdrawal_at(slot, execute_after));
+        assert_eq!(lock.time_until_executable_at(slot, execute_after + 10), 0);
+        assert!(lock.can_execute_withdrawal_at(slot, execute_after + 24 * 3600));
+        
+        // Expired after the grace window
+        assert!(lock.is_withdrawal_expired_at(slot, execute_after + 24 * 3600 + 1));
+        assert!(!lock.can_execute_withdrawal_at(slot, execute_after + 24 * 3600 + 1));
+        
+        // Empty and out-of-range slots are never executable
+        assert!(!lock.can_execute_withdrawal_at(slot + 1, execute_after));
+        assert_eq!(lock.time_until_executable_at(slot + 1, T0), i64::MAX);
+        assert!(!lock.can_execute_withdrawal_at(MAX_PENDING_WITHDRAWALS, execute_after));
+        assert_eq!(lock.time_until_executable_at(MAX_PENDING_WITHDRAWALS, T0), i64::MAX);
+    }
+}
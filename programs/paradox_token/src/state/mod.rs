@@ -11,6 +11,7 @@ pub mod lp_lock;
 pub mod vesting;
 pub mod treasury;
 pub mod armageddon;
+pub mod program_stats;
 
 pub use token_config::*;
 pub use lp_growth::*;
@@ -18,4 +19,19 @@ pub use lp_lock::*;
 pub use vesting::*;
 pub use treasury::*;
 pub use armageddon::*;
+pub use program_stats::*;
+
+use anchor_lang::prelude::*;
+
+/// State account with a layout version marker (see migrate_account)
+pub trait VersionedAccount {
+    /// Current layout version (0 = created before versioning)
+    fn version(&self) -> u8;
+    /// Set layout version after migration
+    fn set_version(&mut self, version: u8);
+    /// Mint this account belongs to (TokenConfig key for ArmageddonState)
+    fn owner_key(&self) -> Pubkey;
+    /// Populate fields that read as zero on accounts older than `old_version` + 1
+    fn populate_new_fields(&mut self, _old_version: u8) {}
+}
 
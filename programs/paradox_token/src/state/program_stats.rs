@@ -0,0 +1,93 @@
+/**
+ * Program Stats State
+ * 
+ * Optional per-deployer aggregate counters across every token a
+ * launchpad operator deployed. Handlers update it only when passed.
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+
+/// Machine-readable aggregate stats (returned via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ProgramStatsSummary {
+    pub deployer: Pubkey,
+    pub tokens_launched: u64,
+    pub total_fees_harvested: u64,
+    pub total_burned: u64,
+    pub total_lp_grown: u64,
+}
+
+/// Program Stats account (one per deployer)
+#[account]
+pub struct ProgramStats {
+    /// Deployer these stats aggregate over (matches TokenConfig.deployer)
+    pub deployer: Pubkey,
+    
+    /// Token configs initialized with this stats account
+    pub tokens_launched: u64,
+    
+    /// Fees harvested into fee vaults (lifetime, all tokens)
+    pub total_fees_harvested: u64,
+    
+    /// Fee tokens burned by distribution (lifetime, all tokens)
+    pub total_burned: u64,
+    
+    /// SOL added to LPs by growth (lifetime, all tokens)
+    pub total_lp_grown: u64,
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+    
+    /// Account layout version
+    pub version: u8,
+    
+    /// Reserved for future use
+    pub reserved: [u8; 62],
+}
+
+impl ProgramStats {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // deployer
+        8 +  // tokens_launched
+        8 +  // total_fees_harvested
+        8 +  // total_burned
+        8 +  // total_lp_grown
+        1 +  // bump
+        1 +  // version
+        62;  // reserved
+    
+    /// Count a token launch
+    /// Record methods saturate - analytics must never fail an instruction
+    pub fn record_launch(&mut self) {
+        self.tokens_launched = self.tokens_launched.saturating_add(1);
+    }
+    
+    /// Add harvested fees
+    pub fn record_fees_harvested(&mut self, amount: u64) {
+        self.total_fees_harvested = self.total_fees_harvested.saturating_add(amount);
+    }
+    
+    /// Add burned fee tokens
+    pub fn record_burn(&mut self, amount: u64) {
+        self.total_burned = self.total_burned.saturating_add(amount);
+    }
+    
+    /// Add SOL deposited into LP by growth
+    pub fn record_lp_growth(&mut self, sol_added: u64) {
+        self.total_lp_grown = self.total_lp_grown.saturating_add(sol_added);
+    }
+    
+    /// Key fields for dashboards, without reserved bytes
+    pub fn summary(&self) -> ProgramStatsSummary {
+        ProgramStatsSummary {
+            deployer: self.deployer,
+            tokens_launched: self.tokens_launched,
+            total_fees_harvested: self.total_fees_harvested,
+            total_burned: self.total_burned,
+            total_lp_grown: self.total_lp_grown,
+        }
+    }
+}
@@ -6,8 +6,41 @@
  */
 
 use anchor_lang::prelude::*;
+use super::VersionedAccount;
 use crate::ParadoxError;
 
+/// Compact config view for dashboards (returned via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ConfigSummary {
+    pub mint: Pubkey,
+    pub transfer_fee_bps: u16,
+    pub lp_share_bps: u16,
+    pub burn_share_bps: u16,
+    pub treasury_share_bps: u16,
+    pub total_fees_collected: u64,
+    pub total_fees_distributed: u64,
+    pub is_paused: bool,
+    pub armageddon_level: u8,
+    /// Pending fee change (0 = none)
+    pub pending_fee_bps: u16,
+    /// When the pending fee change can be executed (0 = none)
+    pub pending_fee_activate_time: i64,
+}
+
+/// Pending fee change timing (returned via return data)
+/// `has_pending == false` is the "no pending change" sentinel - other fields are 0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct FeeChangeStatus {
+    pub has_pending: bool,
+    pub pending_fee_bps: u16,
+    /// Seconds until execute_fee_change is allowed (0 once activated)
+    pub seconds_until_executable: i64,
+    /// Seconds until the execution window closes (0 once closed)
+    pub seconds_until_window_closes: i64,
+    /// True while inside [activate_time, cancel_time)
+    pub is_executable: bool,
+}
+
 /// Token configuration account
 /// Stores fee rates, distribution shares, and admin keys
 #[account]
@@ -58,13 +91,46 @@ pub struct TokenConfig {
     pub pending_fee_activate_time: i64,
     
     /// Timestamp when pending fee change can be cancelled (after activate_time)
+    /// activate_time + fee execution window
     pub pending_fee_cancel_time: i64,
     
     /// Bump seed for PDA
     pub bump: u8,
     
+    /// Total tokens burned (lifetime)
+    pub total_burned: u64,
+    
+    /// Set once by init handler
+    pub is_initialized: bool,
+    
+    /// Account layout version
+    pub version: u8,
+    
+    /// Fee vault balance below which sweep_fee_dust is allowed (0 = default)
+    pub fee_dust_threshold: u64,
+    
+    /// Keeper reward for harvest_and_distribute, bps of harvested amount (0 = off)
+    pub harvester_reward_bps: u16,
+    
+    /// Lowest transfer fee this config may ever announce
+    pub min_fee_bps: u16,
+    
+    /// Highest transfer fee this config may ever announce (0 = unset, protocol defaults)
+    pub max_fee_bps: u16,
+    
+    /// Original admin at init, never changes (ProgramStats attribution)
+    pub deployer: Pubkey,
+    
+    /// Dust floor for vesting/treasury transfers, raw units (0 = legacy MIN_TRANSFER_AMOUNT)
+    /// Recomputed from transfer_fee_bps whenever the fee changes
+    pub min_transfer_amount: u32,
+    
+    /// How long an announced fee change stays executable after the timelock, in hours
+    /// (0 = legacy, DEFAULT_FEE_EXECUTION_WINDOW_HOURS)
+    pub fee_execution_window_hours: u16,
+    
     /// Reserved for future use
-    pub reserved: [u8; 64],
+    pub reserved: [u8; 2],
 }
 
 impl TokenConfig {
@@ -86,7 +152,39 @@ impl TokenConfig {
         8 +  // pending_fee_activate_time
         8 +  // pending_fee_cancel_time
         1 +  // bump
-        64;  // reserved
+        8 +  // total_burned
+        1 +  // is_initialized
+        1 +  // version
+        8 +  // fee_dust_threshold
+        2 +  // harvester_reward_bps
+        2 +  // min_fee_bps
+        2 +  // max_fee_bps
+        32 + // deployer
+        4 +  // min_transfer_amount
+        2 +  // fee_execution_window_hours
+        2;   // reserved
+    
+    /// Set up by its init handler. Accounts created before the flag existed
+    /// read it as 0, so a nonzero `mint` (always written at init) also counts
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized || self.mint != Pubkey::default()
+    }
+    
+    /// `key` is governance and governance is not just the admin again
+    /// (configs created before init required a separate key have governance == admin)
+    pub fn is_independent_governance(&self, key: &Pubkey) -> bool {
+        *key == self.governance && self.governance != self.admin
+    }
+    
+    /// Whether `signer` may trigger Armageddon at `level`
+    /// Levels 1-2: admin or governance. Level 3 (trading pause): independent governance only
+    pub fn can_trigger_level(&self, level: u8, signer: &Pubkey) -> bool {
+        match level {
+            1 | 2 => *signer == self.admin || *signer == self.governance,
+            3 => self.is_independent_governance(signer),
+            _ => false,
+        }
+    }
     
     /// Validate fee shares sum to 100%
     pub fn validate_shares(&self) -> bool {
@@ -96,6 +194,189 @@ impl TokenConfig {
         total == 10_000
     }
     
+    /// Key fields for dashboards, without reserved bytes
+    pub fn summary(&self) -> ConfigSummary {
+        ConfigSummary {
+            mint: self.mint,
+            transfer_fee_bps: self.transfer_fee_bps,
+            lp_share_bps: self.lp_share_bps,
+            burn_share_bps: self.burn_share_bps,
+            treasury_share_bps: self.treasury_share_bps,
+            total_fees_collected: self.total_fees_collected,
+            total_fees_distributed: self.total_fees_distributed,
+            is_paused: self.is_paused,
+            armageddon_level: self.armageddon_level,
+            pending_fee_bps: self.pending_fee_bps,
+            pending_fee_activate_time: self.pending_fee_activate_time,
+        }
+    }
+    
+    /// Pending fee change state machine at `current_time`
+    /// announce → (24h) → activate_time → executable → cancel_time → expired
+    pub fn fee_change_status(&self, current_time: i64) -> FeeChangeStatus {
+        if !self.has_pending_fee_change() {
+            return FeeChangeStatus::default();
+        }
+        
+        FeeChangeStatus {
+            has_pending: true,
+            pending_fee_bps: self.pending_fee_bps,
+            seconds_until_executable: (self.pending_fee_activate_time - current_time).max(0),
+            seconds_until_window_closes: (self.pending_fee_cancel_time - current_time).max(0),
+            is_executable: current_time >= self.pending_fee_activate_time
+                && current_time < self.pending_fee_cancel_time,
+        }
+    }
+    
+    /// Whether a fee change is queued
+    /// Keyed on activate_time, not pending_fee_bps - a queued change to 0 bps is still pending
+    pub fn has_pending_fee_change(&self) -> bool {
+        self.pending_fee_activate_time != 0
+    }
+    
+    /// Queue a fee change at `current_time` (see fee_change_status for the windows)
+    pub fn announce_fee_change(&mut self, new_fee_bps: u16, current_time: i64) -> Result<()> {
+        // Validate new fee against this config's own bounds
+        require!(self.fee_within_bounds(new_fee_bps), ParadoxError::InvalidTransferFee);
+        
+        // No-op change would burn a 24h timelock for nothing
+        require!(new_fee_bps != self.transfer_fee_bps, ParadoxError::InvalidTransferFee);
+        
+        // One queued change at a time - an existing one (even expired) must be cancelled first
+        require!(!self.has_pending_fee_change(), ParadoxError::FeeChangeAlreadyPending);
+        
+        self.pending_fee_bps = new_fee_bps;
+        self.pending_fee_activate_time = current_time
+            .checked_add(crate::FEE_CHANGE_TIMELOCK_SECONDS)
+            .ok_or(ParadoxError::MathOverflow)?;
+        self.pending_fee_cancel_time = self.pending_fee_activate_time
+            .checked_add(self.fee_execution_window_seconds())
+            .ok_or(ParadoxError::MathOverflow)?;
+        
+        Ok(())
+    }
+    
+    /// Apply the queued fee change at `current_time`, returning (old_fee_bps, new_fee_bps)
+    pub fn execute_fee_change(&mut self, current_time: i64) -> Result<(u16, u16)> {
+        require!(self.has_pending_fee_change(), ParadoxError::NoPendingFeeChange);
+        
+        // Don't drop emergency fees mid-Armageddon - execution waits for recovery
+        // (if the window closes first, cancel and re-announce)
+        require!(self.armageddon_level == 0, ParadoxError::ArmageddonActive);
+        
+        require!(
+            current_time >= self.pending_fee_activate_time,
+            ParadoxError::FeeChangeTimelockNotExpired
+        );
+        
+        // Can't execute after the window closes
+        require!(
+            current_time < self.pending_fee_cancel_time,
+            ParadoxError::FeeChangeWindowClosed
+        );
+        
+        let old_fee = self.transfer_fee_bps;
+        let new_fee = self.pending_fee_bps;
+        
+        self.transfer_fee_bps = new_fee;
+        self.last_fee_update = current_time;
+        self.refresh_min_transfer_amount();
+        
+        self.pending_fee_bps = 0;
+        self.pending_fee_activate_time = 0;
+        self.pending_fee_cancel_time = 0;
+        
+        Ok((old_fee, new_fee))
+    }
+    
+    /// Drop the queued fee change at `current_time`, returning the cancelled fee_bps
+    /// Allowed before activate_time or once the window has closed, never while executable
+    pub fn cancel_fee_change(&mut self, current_time: i64) -> Result<u16> {
+        require!(self.has_pending_fee_change(), ParadoxError::NoPendingFeeChange);
+        
+        require!(
+            current_time < self.pending_fee_activate_time ||
+            current_time >= self.pending_fee_cancel_time,
+            ParadoxError::FeeChangeTimelockNotExpired
+        );
+        
+        let cancelled_fee = self.pending_fee_bps;
+        
+        self.pending_fee_bps = 0;
+        self.pending_fee_activate_time = 0;
+        self.pending_fee_cancel_time = 0;
+        
+        Ok(cancelled_fee)
+    }
+    
+    /// Fee bounds in effect as (min, max)
+    /// Configs created before per-config bounds existed get MIN/MAX_TRANSFER_FEE_BPS
+    pub fn fee_bounds(&self) -> (u16, u16) {
+        if self.max_fee_bps == 0 {
+            (crate::MIN_TRANSFER_FEE_BPS, crate::MAX_TRANSFER_FEE_BPS)
+        } else {
+            (self.min_fee_bps, self.max_fee_bps)
+        }
+    }
+    
+    /// Check a fee against this config's bounds
+    pub fn fee_within_bounds(&self, fee_bps: u16) -> bool {
+        let (min, max) = self.fee_bounds();
+        fee_bps >= min && fee_bps <= max
+    }
+    
+    /// Smallest amount whose transfer fee is >= 1 raw unit: ceil(10_000 / fee_bps)
+    /// Fees are charged on raw units, so decimals don't change the floor (300 bps → 34)
+    pub fn min_transfer_for_fee(fee_bps: u16) -> u32 {
+        if fee_bps == 0 {
+            return crate::MIN_TRANSFER_AMOUNT as u32;
+        }
+        
+        let bps = fee_bps as u32;
+        (crate::BPS_DENOMINATOR as u32 + bps - 1) / bps
+    }
+    
+    /// Recompute the dust floor for the current transfer fee
+    pub fn refresh_min_transfer_amount(&mut self) {
+        self.min_transfer_amount = Self::min_transfer_for_fee(self.transfer_fee_bps);
+    }
+    
+    /// Dust floor in effect (falls back to MIN_TRANSFER_AMOUNT for legacy configs)
+    pub fn effective_min_transfer_amount(&self) -> u64 {
+        if self.min_transfer_amount == 0 {
+            crate::MIN_TRANSFER_AMOUNT
+        } else {
+            self.min_transfer_amount as u64
+        }
+    }
+    
+    /// Fee execution window in seconds (legacy configs get the 24h default)
+    pub fn fee_execution_window_seconds(&self) -> i64 {
+        let hours = if self.fee_execution_window_hours == 0 {
+            crate::DEFAULT_FEE_EXECUTION_WINDOW_HOURS
+        } else {
+            self.fee_execution_window_hours
+        };
+        hours as i64 * 3600
+    }
+    
+    /// Dust threshold in effect (falls back to MIN_TRANSFER_AMOUNT when unset)
+    pub fn effective_dust_threshold(&self) -> u64 {
+        if self.fee_dust_threshold == 0 {
+            crate::MIN_TRANSFER_AMOUNT
+        } else {
+            self.fee_dust_threshold
+        }
+    }
+    
+    /// Keeper reward for a harvested amount
+    /// Uses u128 intermediate calculations to prevent overflow
+    pub fn calculate_harvester_reward(&self, harvested: u64) -> u64 {
+        ((harvested as u128)
+            .saturating_mul(self.harvester_reward_bps as u128)
+            / 10_000) as u64
+    }
+    
     /// Calculate fee distribution for a given amount
     /// Uses u128 intermediate calculations to prevent overflow
     pub fn calculate_distribution(&self, fee_amount: u64) -> Result<(u64, u64, u64)> {
@@ -122,3 +403,196 @@ impl TokenConfig {
     }
 }
 
+impl VersionedAccount for TokenConfig {
+    fn version(&self) -> u8 {
+        self.version
+    }
+    
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+    
+    fn owner_key(&self) -> Pubkey {
+        self.mint
+    }
+    
+    fn populate_new_fields(&mut self, old_version: u8) {
+        if old_version == 0 {
+            self.is_initialized = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn new_config() -> TokenConfig {
+        TokenConfig::try_deserialize_unchecked(&mut &vec![0u8; TokenConfig::LEN][..]).unwrap()
+    }
+    
+    #[test]
+    fn governance_equal_to_admin_is_not_independent() {
+        let admin = Pubkey::new_unique();
+        let mut config = new_config();
+        config.admin = admin;
+        config.governance = admin;
+        
+        assert!(!config.is_independent_governance(&admin));
+    }
+    
+    #[test]
+    fn separate_governance_is_independent() {
+        let admin = Pubkey::new_unique();
+        let governance = Pubkey::new_unique();
+        let mut config = new_config();
+        config.admin = admin;
+        config.governance = governance;
+        
+        assert!(config.is_independent_governance(&governance));
+        assert!(!config.is_independent_governance(&admin));
+    }
+    
+    #[test]
+    fn trigger_level_gate() {
+        let admin = Pubkey::new_unique();
+        let governance = Pubkey::new_unique();
+        let mut config = new_config();
+        config.admin = admin;
+        config.governance = governance;
+        
+        // Admin reaches levels 1-2 but not the trading pause
+        assert!(config.can_trigger_level(1, &admin));
+        assert!(config.can_trigger_level(2, &admin));
+        assert!(!config.can_trigger_level(3, &admin));
+        
+        // Governance reaches every level
+        for level in 1..=3 {
+            assert!(config.can_trigger_level(level, &governance));
+        }
+        
+        // Nobody else, and nothing outside 1-3
+        let stranger = Pubkey::new_unique();
+        assert!(!config.can_trigger_level(1, &stranger));
+        assert!(!config.can_trigger_level(3, &stranger));
+        assert!(!config.can_trigger_level(0, &governance));
+        assert!(!config.can_trigger_level(4, &governance));
+        
+        // Legacy config with governance == admin: level 3 is unreachable
+        config.governance = admin;
+        assert!(config.can_trigger_level(2, &admin));
+        assert!(!config.can_trigger_level(3, &admin));
+    }
+    
+    #[test]
+    fn armageddon_between_announce_and_execute_defers_fee_change() {
+        const T0: i64 = 1_700_000_000;
+        
+        let mut config = new_config();
+        config.mint = Pubkey::new_unique();
+        config.transfer_fee_bps = 300;
+        
+        config.announce_fee_change(100, T0).unwrap();
+        let executable_at = config.pending_fee_activate_time;
+        
+        // Armageddon triggers while the change is queued (trigger_handler maxes the fee)
+        config.armageddon_level = 1;
+        config.transfer_fee_bps = config.fee_bounds().1;
+        
+        assert_eq!(
+            config.execute_fee_change(executable_at).unwrap_err(),
+            ParadoxError::ArmageddonActive.into()
+        );
+        assert_eq!(config.transfer_fee_bps, config.fee_bounds().1);
+        assert_eq!(config.pending_fee_bps, 100);
+        
+        // After recovery the queued change goes through inside its window
+        config.armageddon_level = 0;
+        assert_eq!(config.execute_fee_change(executable_at).unwrap().1, 100);
+        assert_eq!(config.transfer_fee_bps, 100);
+        assert_eq!(config.pending_fee_activate_time, 0);
+    }
+    
+    fn config_with_fee(fee_bps: u16) -> TokenConfig {
+        let mut config = new_config();
+        config.mint = Pubkey::new_unique();
+        config.min_fee_bps = 0;
+        config.max_fee_bps = 500;
+        config.transfer_fee_bps = fee_bps;
+        config
+    }
+    
+    #[test]
+    fn announce_while_pending_is_rejected() {
+        const T0: i64 = 1_700_000_000;
+        
+        let mut config = config_with_fee(300);
+        config.announce_fee_change(100, T0).unwrap();
+        let (activate, close) = (config.pending_fee_activate_time, config.pending_fee_cancel_time);
+        
+        // Still timelocked, executable, and expired - all block a second announce
+        for t in [T0 + 1, activate, close, close + 1_000_000] {
+            assert_eq!(
+                config.announce_fee_change(200, t).unwrap_err(),
+                ParadoxError::FeeChangeAlreadyPending.into()
+            );
+            assert_eq!(config.pending_fee_bps, 100);
+            assert_eq!(config.pending_fee_activate_time, activate);
+        }
+        
+        // Expired change can't execute; cancel it and re-announce
+        assert_eq!(
+            config.execute_fee_change(close).unwrap_err(),
+            ParadoxError::FeeChangeWindowClosed.into()
+        );
+        assert_eq!(config.cancel_fee_change(close).unwrap(), 100);
+        assert!(!config.has_pending_fee_change());
+        config.announce_fee_change(200, close).unwrap();
+        assert_eq!(config.pending_fee_bps, 200);
+    }
+    
+    #[test]
+    fn cancel_is_blocked_while_executable() {
+        const T0: i64 = 1_700_000_000;
+        
+        let mut config = config_with_fee(300);
+        config.announce_fee_change(100, T0).unwrap();
+        let activate = config.pending_fee_activate_time;
+        
+        assert_eq!(
+            config.cancel_fee_change(activate).unwrap_err(),
+            ParadoxError::FeeChangeTimelockNotExpired.into()
+        );
+        assert_eq!(config.cancel_fee_change(activate - 1).unwrap(), 100);
+        assert_eq!(
+            config.cancel_fee_change(activate - 1).unwrap_err(),
+            ParadoxError::NoPendingFeeChange.into()
+        );
+    }
+    
+    #[test]
+    fn zero_fee_change_is_still_pending() {
+        const T0: i64 = 1_700_000_000;
+        
+        // min_fee_bps = 0 allows announcing a change to 0 bps
+        let mut config = config_with_fee(300);
+        config.announce_fee_change(0, T0).unwrap();
+        assert!(config.has_pending_fee_change());
+        assert!(config.fee_change_status(T0).has_pending);
+        assert_eq!(
+            config.announce_fee_change(100, T0).unwrap_err(),
+            ParadoxError::FeeChangeAlreadyPending.into()
+        );
+        
+        // Cancellable before activation...
+        assert_eq!(config.cancel_fee_change(T0 + 1).unwrap(), 0);
+        assert!(!config.has_pending_fee_change());
+        
+        // ...and executable inside the window
+        config.announce_fee_change(0, T0).unwrap();
+        let activate = config.pending_fee_activate_time;
+        assert_eq!(config.execute_fee_change(activate).unwrap(), (300, 0));
+        assert_eq!(config.transfer_fee_bps, 0);
+        assert!(!config.has_pending_fee_change());
+    }
+}
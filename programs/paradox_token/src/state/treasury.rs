@@ -8,6 +8,41 @@
  */
 
 use anchor_lang::prelude::*;
+use super::VersionedAccount;
+use crate::ParadoxError;
+
+/// Maximum number of approvers for large treasury withdrawals
+pub const MAX_TREASURY_APPROVERS: usize = 5;
+
+/// Maximum approved recipients on a treasury allowlist
+pub const MAX_TREASURY_RECIPIENTS: usize = 8;
+
+/// Machine-readable treasury data (returned via return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct TreasuryStatus {
+    /// Total tokens held
+    pub balance: u64,
+    /// Spent in the effective current period (0 if the period has elapsed)
+    pub spent_this_period: u64,
+    /// Spendable now (post-reset value if the period has elapsed)
+    pub max_spendable: u64,
+    /// Timestamp when the spending period next resets
+    pub next_period_reset: i64,
+    /// Pending withdrawal amount (0 = none)
+    pub pending_amount: u64,
+    /// Pending withdrawal recipient
+    pub pending_recipient: Pubkey,
+    /// Timestamp when pending withdrawal can be executed
+    pub pending_execute_after: i64,
+    /// Approvals recorded on the pending withdrawal
+    pub pending_approvals: u8,
+    /// Approvals needed before execution (0 = below large withdrawal threshold)
+    pub required_approvals: u8,
+    /// ID of the pending proposal (nonce of the latest proposal if none pending)
+    pub proposal_nonce: u64,
+    /// Held against the period budget by the pending proposal
+    pub reserved_this_period: u64,
+}
 
 /// DAO Treasury Vault account
 #[account]
@@ -57,8 +92,46 @@ pub struct DaoTreasuryVault {
     /// Bump seed for PDA
     pub bump: u8,
     
+    /// Set once by init handler
+    pub is_initialized: bool,
+    
+    /// Account layout version
+    pub version: u8,
+    
+    /// Proposed new governance (Pubkey::default() = none), must accept
+    pub pending_governance: Pubkey,
+    
+    /// Withdrawals above this amount need approver signatures (0 = disabled)
+    pub large_withdrawal_threshold: u64,
+    
+    /// Distinct approvals required for a large withdrawal
+    pub required_approvals: u8,
+    
+    /// Approver set (Pubkey::default() = empty slot)
+    pub approvers: [Pubkey; MAX_TREASURY_APPROVERS],
+    
+    /// Approvals on the pending withdrawal (bit i = approvers[i] approved)
+    pub pending_approvals: u8,
+    
+    /// Payout fee mode (0 = net / recipient bears fee, 1 = gross-up, see fee_mode.rs)
+    pub fee_mode: u8,
+    
+    /// Incremented on every proposal - stable ID for off-chain voting, must match on execute
+    pub proposal_nonce: u64,
+    
+    /// Proposed but not yet executed - counts against the period budget
+    /// Carried across period resets until the proposal executes or is cancelled
+    pub reserved_this_period: u64,
+    
+    /// Proposals must target a recipient on the TreasuryAllowlist (one-way opt-in)
+    pub allowlist_enabled: bool,
+    
+    /// Gross amount the pending proposal holds in reserved_this_period
+    /// (0 on proposals made before it existed, which reserved pending_amount)
+    pub pending_reserved: u64,
+    
     /// Reserved for future use
-    pub reserved: [u8; 32],
+    pub reserved: [u8; 4],
 }
 
 impl DaoTreasuryVault {
@@ -78,7 +151,25 @@ impl DaoTreasuryVault {
         8 +  // timelock_seconds
         8 +  // total_withdrawn
         1 +  // bump
-        32;  // reserved
+        1 +  // is_initialized
+        1 +  // version
+        32 + // pending_governance
+        8 +  // large_withdrawal_threshold
+        1 +  // required_approvals
+        32 * MAX_TREASURY_APPROVERS + // approvers
+        1 +  // pending_approvals
+        1 +  // fee_mode
+        8 +  // proposal_nonce
+        8 +  // reserved_this_period
+        1 +  // allowlist_enabled
+        8 +  // pending_reserved
+        4;   // reserved
+    
+    /// Set up by its init handler. Accounts created before the flag existed
+    /// read it as 0, so a nonzero `mint` (always written at init) also counts
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized || self.mint != Pubkey::default()
+    }
     
     /// Get maximum spendable amount in current period
     /// Uses u128 intermediate calculations to prevent overflow
@@ -88,23 +179,252 @@ impl DaoTreasuryVault {
             .checked_div(10_000)
             .unwrap_or(0)) as u64;
         
-        max_spend.saturating_sub(self.spent_this_period)
+        max_spend
+            .saturating_sub(self.spent_this_period)
+            .saturating_sub(self.reserved_this_period)
+    }
+    
+    /// Number of full periods elapsed since `period_start`
+    pub fn elapsed_periods(&self, current_time: i64) -> i64 {
+        if self.period_seconds <= 0 || current_time <= self.period_start {
+            return 0;
+        }
+        (current_time - self.period_start) / self.period_seconds
     }
     
     /// Check if period has reset
     pub fn should_reset_period(&self, current_time: i64) -> bool {
-        current_time >= self.period_start + self.period_seconds
+        self.elapsed_periods(current_time) > 0
     }
     
     /// Reset period tracking
+    /// Snaps period_start to the aligned boundary (period_start + n * period_seconds)
+    /// so period windows stay deterministic regardless of when the reset happens
     pub fn reset_period(&mut self, current_time: i64) {
-        self.period_start = current_time;
+        let elapsed = self.elapsed_periods(current_time);
+        self.period_start = self.period_start
+            .saturating_add(elapsed.saturating_mul(self.period_seconds));
         self.spent_this_period = 0;
     }
     
+    /// Treasury status as seen at `current_time`
+    /// If the period has elapsed, reports the values a reset would produce
+    pub fn get_status(&self, current_time: i64) -> TreasuryStatus {
+        let mut view = TreasuryStatus {
+            balance: self.balance,
+            spent_this_period: self.spent_this_period,
+            max_spendable: self.max_spendable(),
+            next_period_reset: self.period_start.saturating_add(self.period_seconds),
+            pending_amount: self.pending_amount,
+            pending_recipient: self.pending_recipient,
+            pending_execute_after: self.pending_execute_after,
+            pending_approvals: self.approval_count(),
+            required_approvals: if self.requires_approvals() { self.required_approvals } else { 0 },
+            proposal_nonce: self.proposal_nonce,
+            reserved_this_period: self.reserved_this_period,
+        };
+        
+        if self.should_reset_period(current_time) {
+            let mut reset = self.clone();
+            reset.reset_period(current_time);
+            view.spent_this_period = reset.spent_this_period;
+            view.max_spendable = reset.max_spendable();
+            view.next_period_reset = reset.period_start.saturating_add(reset.period_seconds);
+        }
+        
+        view
+    }
+    
+    /// Check if the pending withdrawal needs approver signatures
+    pub fn requires_approvals(&self) -> bool {
+        self.large_withdrawal_threshold > 0 && self.pending_amount > self.large_withdrawal_threshold
+    }
+    
+    /// Index of `key` in the approver set
+    pub fn approver_index(&self, key: &Pubkey) -> Option<usize> {
+        if *key == Pubkey::default() {
+            return None;
+        }
+        self.approvers.iter().position(|a| a == key)
+    }
+    
+    /// Number of distinct approvals recorded on the pending withdrawal
+    pub fn approval_count(&self) -> u8 {
+        self.pending_approvals.count_ones() as u8
+    }
+    
+    /// Check if the pending withdrawal has enough approvals (or doesn't need any)
+    pub fn has_required_approvals(&self) -> bool {
+        !self.requires_approvals() || self.approval_count() >= self.required_approvals
+    }
+    
+    /// Check if a proposal is waiting for execution
+    pub fn has_pending_withdrawal(&self) -> bool {
+        self.pending_amount > 0
+    }
+    
+    /// Hold `amount` against the period budget for a new proposal
+    pub fn reserve(&mut self, amount: u64) -> Result<()> {
+        self.reserved_this_period = self.reserved_this_period
+            .checked_add(amount)
+            .ok_or(ParadoxError::MathOverflow)?;
+        Ok(())
+    }
+    
+    /// Clear the pending proposal and release its reservation
+    /// (proposal_nonce is kept - it only ever increases)
+    pub fn clear_pending(&mut self) {
+        // Saturating: proposals made before reservations existed hold none
+        let held = if self.pending_reserved == 0 { self.pending_amount } else { self.pending_reserved };
+        self.reserved_this_period = self.reserved_this_period.saturating_sub(held);
+        self.pending_reserved = 0;
+        self.pending_amount = 0;
+        self.pending_recipient = Pubkey::default();
+        self.pending_reason = [0u8; 128];
+        self.pending_execute_after = 0;
+        self.pending_approvals = 0;
+    }
+    
     /// Check if withdrawal can be executed
     pub fn can_execute_withdrawal(&self, current_time: i64) -> bool {
         self.pending_amount > 0 && current_time >= self.pending_execute_after
     }
 }
 
+impl VersionedAccount for DaoTreasuryVault {
+    fn version(&self) -> u8 {
+        self.version
+    }
+    
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+    
+    fn owner_key(&self) -> Pubkey {
+        self.mint
+    }
+    
+    fn populate_new_fields(&mut self, old_version: u8) {
+        if old_version == 0 {
+            self.is_initialized = true;
+        }
+    }
+}
+
+/// Approved treasury recipients (separate PDA, see DaoTreasuryVault.allowlist_enabled)
+/// Edited only by allowlist_admin, so a compromised governance key alone
+/// can't redirect the treasury to a new address
+#[account]
+pub struct TreasuryAllowlist {
+    /// Treasury this allowlist guards
+    pub treasury: Pubkey,
+    
+    /// Only key that can add / remove recipients (separate from governance)
+    pub allowlist_admin: Pubkey,
+    
+    /// Approved recipients (Pubkey::default() = empty slot)
+    pub recipients: [Pubkey; MAX_TREASURY_RECIPIENTS],
+    
+    /// Bump seed for PDA
+    pub bump: u8,
+    
+    /// Account layout version
+    pub version: u8,
+    
+    /// Reserved for future use
+    pub reserved: [u8; 32],
+}
+
+impl TreasuryAllowlist {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // treasury
+        32 + // allowlist_admin
+        32 * MAX_TREASURY_RECIPIENTS + // recipients
+        1 +  // bump
+        1 +  // version
+        32;  // reserved
+    
+    /// Check if `recipient` is approved
+    pub fn contains(&self, recipient: &Pubkey) -> bool {
+        *recipient != Pubkey::default() && self.recipients.contains(recipient)
+    }
+    
+    /// Add `recipient` to the first free slot
+    pub fn add(&mut self, recipient: Pubkey) -> Result<()> {
+        require!(
+            recipient != Pubkey::default() && !self.contains(&recipient),
+            ParadoxError::InvalidAllowlistRecipient
+        );
+        let slot = self.recipients
+            .iter()
+            .position(|r| *r == Pubkey::default())
+            .ok_or(ParadoxError::AllowlistFull)?;
+        self.recipients[slot] = recipient;
+        Ok(())
+    }
+    
+    /// Remove `recipient` (errors if not on the list)
+    pub fn remove(&mut self, recipient: &Pubkey) -> Result<()> {
+        require!(self.contains(recipient), ParadoxError::InvalidAllowlistRecipient);
+        let slot = self.recipients
+            .iter()
+            .position(|r| r == recipient)
+            .ok_or(ParadoxError::InvalidAllowlistRecipient)?;
+        self.recipients[slot] = Pubkey::default();
+        Ok(())
+    }
+    
+    /// Number of approved recipients
+    pub fn count(&self) -> usize {
+        self.recipients.iter().filter(|r| **r != Pubkey::default()).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const T0: i64 = 1_700_000_000;
+    const PERIOD: i64 = 86_400;
+    
+    fn vault_spent(spent: u64) -> DaoTreasuryVault {
+        let mut vault = DaoTreasuryVault::try_deserialize_unchecked(
+            &mut &vec![0u8; DaoTreasuryVault::LEN][..]
+        ).unwrap();
+        vault.period_seconds = PERIOD;
+        vault.period_start = T0;
+        vault.spent_this_period = spent;
+        vault
+    }
+    
+    #[test]
+    fn reset_period_snaps_to_boundary() {
+        let mut vault = vault_spent(5_000);
+        let now = T0 + 2 * PERIOD + 3_600;
+        
+        assert_eq!(vault.elapsed_periods(now), 2);
+        assert!(vault.should_reset_period(now));
+        
+        vault.reset_period(now);
+        assert_eq!(vault.period_start, T0 + 2 * PERIOD);
+        assert_eq!(vault.spent_this_period, 0);
+        assert_eq!(vault.elapsed_periods(now), 0);
+    }
+    
+    #[test]
+    fn reset_period_exactly_on_boundary() {
+        let mut vault = vault_spent(5_000);
+        
+        // One second short of the boundary is still the current period
+        assert_eq!(vault.elapsed_periods(T0 + PERIOD - 1), 0);
+        assert!(!vault.should_reset_period(T0 + PERIOD - 1));
+        
+        let now = T0 + 2 * PERIOD;
+        assert_eq!(vault.elapsed_periods(now), 2);
+        
+        vault.reset_period(now);
+        assert_eq!(vault.period_start, now);
+        assert_eq!(vault.spent_this_period, 0);
+        assert!(!vault.should_reset_period(now));
+    }
+}
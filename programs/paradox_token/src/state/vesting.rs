@@ -9,6 +9,23 @@
  */
 
 use anchor_lang::prelude::*;
+use super::VersionedAccount;
+use crate::ParadoxError;
+
+/// Claim preview for dev UIs (returned via return data)
+/// Separates "already requested and waiting" from "additionally requestable now"
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ClaimPreview {
+    /// Amount already requested and sitting in the timelock
+    pub pending_amount: u64,
+    /// When the pending amount becomes executable (0 if nothing pending)
+    pub unlock_time: i64,
+    /// Seconds until a new request passes the cooldown (0 once passed)
+    pub seconds_until_cooldown_passes: i64,
+    /// Amount a new request could ask for right now
+    /// Always 0 while a request is pending - it must be executed first
+    pub max_additional_requestable_now: u64,
+}
 
 /// Dev Vesting Vault account
 /// Locks dev tokens with cliff period and progressive unlocks
@@ -27,6 +44,8 @@ pub struct DevVestingVault {
     pub total_allocation: u64,
     
     /// Amount liquid at TGE (can be 0 for full cliff)
+    /// Never enters the vault - it stays with the dev at TGE and is recorded
+    /// for reporting only. The vault holds exactly total_locked.
     pub liquid_at_tge: u64,
     
     /// Total amount locked (total_allocation - liquid_at_tge)
@@ -68,8 +87,23 @@ pub struct DevVestingVault {
     /// Bump seed for PDA
     pub bump: u8,
     
+    /// Year 1 unlock rate in bps (default 500 = 5%)
+    pub year1_rate_bps: u16,
+    
+    /// Year 2+ unlock rate in bps (default 1000 = 10%)
+    pub year2_rate_bps: u16,
+    
+    /// Set once by init handler
+    pub is_initialized: bool,
+    
+    /// Account layout version
+    pub version: u8,
+    
+    /// Payout fee mode (0 = net / recipient bears fee, 1 = gross-up, see fee_mode.rs)
+    pub fee_mode: u8,
+    
     /// Reserved for future use
-    pub reserved: [u8; 32],
+    pub reserved: [u8; 25],
 }
 
 impl DevVestingVault {
@@ -92,7 +126,34 @@ impl DevVestingVault {
         2 +  // unlock_rate_bps
         8 +  // total_unlocked
         1 +  // bump
-        32;  // reserved
+        2 +  // year1_rate_bps
+        2 +  // year2_rate_bps
+        1 +  // is_initialized
+        1 +  // version
+        1 +  // fee_mode
+        25;  // reserved
+    
+    /// Set up by its init handler. Accounts created before the flag existed
+    /// read it as 0, so a nonzero `mint` (always written at init) also counts
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized || self.mint != Pubkey::default()
+    }
+    
+    /// Validate a schedule before init
+    /// vesting_seconds <= cliff_seconds would vest instantly at the cliff
+    pub fn validate_schedule(
+        total_allocation: u64,
+        liquid_at_tge: u64,
+        cliff_seconds: i64,
+        vesting_seconds: i64,
+    ) -> Result<()> {
+        require!(
+            cliff_seconds >= 0 && vesting_seconds > cliff_seconds,
+            ParadoxError::InvalidVestingSchedule
+        );
+        require!(liquid_at_tge <= total_allocation, ParadoxError::InvalidLiquidAmount);
+        Ok(())
+    }
     
     /// Check if cliff period has passed
     pub fn cliff_passed(&self, current_time: i64) -> bool {
@@ -114,9 +175,13 @@ impl DevVestingVault {
     /// Calculate maximum unlockable amount based on rate
     /// Uses u128 intermediate calculations to prevent overflow
     pub fn max_unlockable(&self) -> u64 {
-        // Rate is in bps (e.g., 500 = 5%)
+        self.max_unlockable_with_rate(self.unlock_rate_bps)
+    }
+    
+    /// Same as max_unlockable, for an explicit rate in bps (e.g., 500 = 5%)
+    fn max_unlockable_with_rate(&self, rate_bps: u16) -> u64 {
         ((self.locked_amount as u128)
-            .saturating_mul(self.unlock_rate_bps as u128)
+            .saturating_mul(rate_bps as u128)
             .checked_div(10_000)
             .unwrap_or(0)) as u64
     }
@@ -142,19 +207,172 @@ impl DevVestingVault {
             / (vesting_time as u64).max(1)
     }
     
-    /// Update unlock rate based on time since TGE
-    /// Year 1: 5% per request
-    /// Year 2+: 10% per request
-    pub fn update_unlock_rate(&mut self, current_time: i64) {
+    /// Share of total_locked vested at `current_time` in bps (0 before the cliff)
+    /// An empty allocation counts as fully vested
+    pub fn percent_vested_bps(&self, current_time: i64) -> u16 {
+        if self.total_locked == 0 {
+            return 10_000;
+        }
+        ((self.vested_amount(current_time) as u128)
+            .saturating_mul(10_000)
+            / self.total_locked as u128)
+            .min(10_000) as u16
+    }
+    
+    /// Calculate total claimable including TGE liquid portion
+    /// liquid_at_tge + vested_amount(now) - total_unlocked, floored at 0
+    pub fn total_claimable(&self, current_time: i64) -> u64 {
+        self.liquid_at_tge
+            .saturating_add(self.vested_amount(current_time))
+            .saturating_sub(self.total_unlocked)
+    }
+    
+    /// Claimable out of the vault itself: vested_amount(now) - total_unlocked, floored at 0
+    /// (liquid_at_tge never enters the vault)
+    pub fn claimable_from_vault(&self, current_time: i64) -> u64 {
+        self.vested_amount(current_time)
+            .saturating_sub(self.total_unlocked)
+    }
+    
+    /// Check if vesting has fully completed (everything unlocked, nothing pending)
+    pub fn is_complete(&self) -> bool {
+        self.locked_amount == 0
+            && self.pending_amount == 0
+            && self.total_unlocked == self.total_locked
+    }
+    
+    /// Unlock rate that applies at a given time
+    /// Year 1: year1_rate_bps per request
+    /// Year 2+: year2_rate_bps per request
+    pub fn unlock_rate_at(&self, current_time: i64) -> u16 {
         let months_since_tge = (current_time - self.initialized_at) / (30 * 24 * 60 * 60);
         
         if months_since_tge >= 18 {
             // Year 2+ (after month 18)
-            self.unlock_rate_bps = 1000; // 10%
+            self.year2_rate_bps
         } else {
             // Year 1 (months 7-18)
-            self.unlock_rate_bps = 500; // 5%
+            self.year1_rate_bps
+        }
+    }
+    
+    /// Update unlock rate based on time since TGE
+    pub fn update_unlock_rate(&mut self, current_time: i64) {
+        self.unlock_rate_bps = self.unlock_rate_at(current_time);
+    }
+    
+    /// Preview what the dev can see/request at a given time
+    /// Mirrors the checks in request_unlock without mutating state
+    pub fn claim_preview(&self, current_time: i64) -> ClaimPreview {
+        let cooldown_end = self.last_request_time.saturating_add(self.cooldown_seconds);
+        
+        let max_additional_requestable_now = if self.pending_amount > 0
+            || !self.cliff_passed(current_time)
+            || !self.cooldown_passed(current_time)
+        {
+            0
+        } else {
+            let by_rate = self.max_unlockable_with_rate(self.unlock_rate_at(current_time));
+            let by_vesting = self.vested_amount(current_time)
+                .saturating_sub(self.total_unlocked);
+            by_rate.min(by_vesting)
+        };
+        
+        ClaimPreview {
+            pending_amount: self.pending_amount,
+            unlock_time: if self.pending_amount > 0 { self.unlock_time } else { 0 },
+            seconds_until_cooldown_passes: cooldown_end.saturating_sub(current_time).max(0),
+            max_additional_requestable_now,
+        }
+    }
+}
+
+impl VersionedAccount for DevVestingVault {
+    fn version(&self) -> u8 {
+        self.version
+    }
+    
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+    
+    fn owner_key(&self) -> Pubkey {
+        self.mint
+    }
+    
+    fn populate_new_fields(&mut self, old_version: u8) {
+        if old_version == 0 {
+            self.is_initialized = true;
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const DAY: i64 = 86_400;
+    
+    #[test]
+    fn validate_schedule_rejects_degenerate_schedules() {
+        // Vesting must end strictly after the cliff
+        assert_eq!(
+            DevVestingVault::validate_schedule(1_000, 0, 30 * DAY, 30 * DAY).unwrap_err(),
+            ParadoxError::InvalidVestingSchedule.into()
+        );
+        assert_eq!(
+            DevVestingVault::validate_schedule(1_000, 0, 30 * DAY, 10 * DAY).unwrap_err(),
+            ParadoxError::InvalidVestingSchedule.into()
+        );
+        
+        // Negative cliff
+        assert_eq!(
+            DevVestingVault::validate_schedule(1_000, 0, -1, 365 * DAY).unwrap_err(),
+            ParadoxError::InvalidVestingSchedule.into()
+        );
+        
+        // More liquid at TGE than allocated
+        assert_eq!(
+            DevVestingVault::validate_schedule(1_000, 1_001, 30 * DAY, 365 * DAY).unwrap_err(),
+            ParadoxError::InvalidLiquidAmount.into()
+        );
+    }
+    
+    #[test]
+    fn validate_schedule_accepts_edge_schedules() {
+        assert!(DevVestingVault::validate_schedule(1_000, 0, 0, 1).is_ok());
+        assert!(DevVestingVault::validate_schedule(1_000, 1_000, 30 * DAY, 365 * DAY).is_ok());
+    }
+    
+    #[test]
+    fn total_claimable_over_the_schedule() {
+        const T0: i64 = 1_700_000_000;
+        
+        // 1_100 allocated, 100 liquid at TGE, 1_000 vesting linearly over 300 days after a 30-day cliff
+        let mut vault = DevVestingVault::try_deserialize_unchecked(&mut &vec![0u8; DevVestingVault::LEN][..]).unwrap();
+        vault.total_allocation = 1_100;
+        vault.liquid_at_tge = 100;
+        vault.total_locked = 1_000;
+        vault.locked_amount = 1_000;
+        vault.initialized_at = T0;
+        vault.cliff_seconds = 30 * DAY;
+        vault.vesting_seconds = 330 * DAY;
+        
+        // t=0: only the TGE liquid amount
+        assert_eq!(vault.total_claimable(T0), 100);
+        
+        // Halfway through the linear part: TGE liquid + half the locked amount
+        assert_eq!(vault.total_claimable(T0 + 180 * DAY), 600);
+        
+        // Fully vested (and past the end): the whole allocation
+        assert_eq!(vault.total_claimable(T0 + 330 * DAY), 1_100);
+        assert_eq!(vault.total_claimable(T0 + 1_000 * DAY), 1_100);
+        
+        // Unlocked amounts come off the total; the vault-only view leaves TGE liquid out
+        vault.total_unlocked = 200;
+        assert_eq!(vault.total_claimable(T0 + 180 * DAY), 400);
+        assert_eq!(vault.claimable_from_vault(T0), 0);
+        assert_eq!(vault.claimable_from_vault(T0 + 180 * DAY), 300);
+        assert_eq!(vault.claimable_from_vault(T0 + 330 * DAY), 800);
+    }
+}
@@ -0,0 +1,82 @@
+/**
+ * Transfer Hook Compatible Transfers
+ * 
+ * A mint with the Token-2022 TransferHook extension makes every
+ * transfer_checked CPI into the hook program, which needs extra accounts
+ * (hook program, its extra-account-metas PDA and whatever that PDA lists).
+ * A plain transfer_checked doesn't carry them and fails.
+ * 
+ * Payout handlers (vesting, treasury, LP withdrawal) go through
+ * transfer_checked_with_hook: with a hook, the extra accounts are resolved
+ * from the instruction's remaining_accounts; without one it is a plain
+ * transfer_checked, so nothing changes for hook-free mints.
+ * 
+ * Made by LabsX402 for Solana
+ * https://x.com/LabsX402
+ */
+
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TransferChecked, transfer_checked};
+use anchor_spl::token_interface::spl_token_2022::{
+    self,
+    extension::{transfer_hook, StateWithExtensions},
+    onchain::invoke_transfer_checked,
+    state::Mint as SplMint,
+};
+
+/// Check if the mint has a TransferHook extension with a hook program set
+/// Legacy SPL Token mints never do
+pub fn mint_has_transfer_hook(mint: &AccountInfo) -> Result<bool> {
+    if mint.owner != &spl_token_2022::ID {
+        return Ok(false);
+    }
+    
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<SplMint>::unpack(&data)?;
+    Ok(transfer_hook::get_program_id(&state).is_some())
+}
+
+/// transfer_checked that also works when the mint has a transfer hook
+/// `remaining_accounts` must hold the hook's extra accounts when it does (ignored otherwise)
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_checked_with_hook<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    decimals: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if mint_has_transfer_hook(mint)? {
+        invoke_transfer_checked(
+            token_program.key,
+            from.clone(),
+            mint.clone(),
+            to.clone(),
+            authority.clone(),
+            remaining_accounts,
+            amount,
+            decimals,
+            signer_seeds,
+        )?;
+        return Ok(());
+    }
+    
+    transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TransferChecked {
+                from: from.clone(),
+                mint: mint.clone(),
+                to: to.clone(),
+                authority: authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )
+}